@@ -139,7 +139,7 @@ pub fn submit_rwa_proof(
     // Until full Groth16 on-chain verification is available, only the trusted
     // UPE Notary can submit proofs. This prevents arbitrary proof injection.
     let attester = ATTESTER.load(deps.storage)?;
-    if info.sender.to_string() != attester {
+    if info.sender.as_str() != attester {
         return Err(StdError::generic_err(
             "Unauthorized: only the UPE Notary attester may submit proofs. \
              Full Groth16 on-chain verification is planned for Phase 2.",
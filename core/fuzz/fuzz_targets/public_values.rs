@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use universal_privacy_engine_core::fuzzing;
+
+fuzz_target!(|data: &[u8]| {
+    fuzzing::public_values_from_wire(data);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use universal_privacy_engine_core::fuzzing;
+
+fuzz_target!(|data: &[u8]| {
+    fuzzing::claim_from_bytes(data);
+});
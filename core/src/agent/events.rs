@@ -0,0 +1,325 @@
+//! # Verifier Events
+//!
+//! Parses the events emitted by on-chain verifiers into a common shape so that
+//! clients can confirm a submission committed the values they proved.
+//!
+//! ## Event Layouts
+//!
+//! - **Solana**: Anchor `ProofVerified` event, logged as
+//!   `Program data: base64(sha256("event:ProofVerified")[..8] || borsh(event))`
+//! - **Stellar**: Contract event with topic `proof_verified` and data
+//!   `pubkey (32) || threshold (u64 BE) || count (u64 BE) || timestamp (u64 BE)`
+//! - **EVM**: `ProofVerified(bytes32 indexed institutionalPubkey, uint256 threshold,
+//!   uint256 count, uint256 timestamp)`
+//! - **CosmWasm (Mantra)**: `wasm` event attributes `institutional_pubkey` (hex),
+//!   `threshold`, `verification_count`, `timestamp`
+
+use super::orchestrator::OrchestrationError;
+use crate::rwa::{RwaPublicValues, NO_MAX_THRESHOLD};
+use crate::ChainType;
+use async_trait::async_trait;
+use borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Name of the event emitted by every verifier on successful verification
+pub const VERIFIED_EVENT_NAME: &str = "ProofVerified";
+
+/// Stellar topic for the verification event
+pub const STELLAR_VERIFIED_TOPIC: &str = "proof_verified";
+
+/// Solidity signature of the EVM verification event
+pub const EVM_VERIFIED_SIGNATURE: &str = "ProofVerified(bytes32,uint256,uint256,uint256)";
+
+/// Raw event data as returned by a chain's RPC
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RawChainEvent {
+    /// Solana transaction log lines
+    Solana { logs: Vec<String> },
+
+    /// Soroban contract event topics and data payload
+    Stellar { topics: Vec<String>, data: Vec<u8> },
+
+    /// EVM log topics and ABI-encoded data
+    Evm { topics: Vec<[u8; 32]>, data: Vec<u8> },
+
+    /// CosmWasm `wasm` event attributes
+    Cosmwasm { attributes: Vec<(String, String)> },
+}
+
+/// A verification event normalized across chains
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifiedEvent {
+    /// Institution whose claim was verified
+    pub institutional_pubkey: [u8; 32],
+
+    /// Threshold the institution was proven to meet
+    pub threshold: u64,
+
+    /// Verifier's running verification count after this event
+    pub count: u64,
+
+    /// Block/ledger timestamp of the verification
+    pub timestamp: i64,
+}
+
+/// Borsh layout of the Anchor `ProofVerified` event
+#[derive(BorshDeserialize)]
+struct AnchorProofVerified {
+    institutional_pubkey: [u8; 32],
+    threshold: u64,
+    count: u64,
+    timestamp: i64,
+}
+
+impl VerifiedEvent {
+    /// Check whether this event committed the expected public values
+    ///
+    /// Verifier events carry only the institution and threshold, so the
+    /// freshness window is not compared. A bounded range claim (`max_threshold`
+    /// other than `NO_MAX_THRESHOLD`) never matches, since the event can't
+    /// confirm the upper bound.
+    pub fn matches(&self, expected: &RwaPublicValues) -> bool {
+        expected.max_threshold == NO_MAX_THRESHOLD
+            && self.institutional_pubkey == expected.institutional_pubkey
+            && self.threshold == expected.threshold
+    }
+
+    /// Parse a chain's raw event into a `VerifiedEvent`
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrchestrationError::EventParse` if the event is missing or malformed
+    pub fn from_raw(raw: &RawChainEvent) -> Result<Self, OrchestrationError> {
+        match raw {
+            RawChainEvent::Solana { logs } => Self::from_solana_logs(logs),
+            RawChainEvent::Stellar { topics, data } => Self::from_stellar(topics, data),
+            RawChainEvent::Evm { topics, data } => Self::from_evm_log(topics, data),
+            RawChainEvent::Cosmwasm { attributes } => Self::from_cosmwasm_attributes(attributes),
+        }
+    }
+
+    fn from_solana_logs(logs: &[String]) -> Result<Self, OrchestrationError> {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        let discriminator = anchor_event_discriminator(VERIFIED_EVENT_NAME);
+
+        for line in logs.iter().rev() {
+            let Some(payload) = line.strip_prefix("Program data: ") else {
+                continue;
+            };
+            let Ok(bytes) = STANDARD.decode(payload.trim()) else {
+                continue;
+            };
+            if bytes.len() < 8 || bytes[..8] != discriminator {
+                continue;
+            }
+
+            let event = AnchorProofVerified::try_from_slice(&bytes[8..])
+                .map_err(|e| OrchestrationError::EventParse(format!("Invalid Solana event: {}", e)))?;
+            return Ok(Self {
+                institutional_pubkey: event.institutional_pubkey,
+                threshold: event.threshold,
+                count: event.count,
+                timestamp: event.timestamp,
+            });
+        }
+
+        Err(OrchestrationError::EventParse("No ProofVerified event in Solana logs".into()))
+    }
+
+    fn from_stellar(topics: &[String], data: &[u8]) -> Result<Self, OrchestrationError> {
+        if !topics.iter().any(|t| t == STELLAR_VERIFIED_TOPIC) {
+            return Err(OrchestrationError::EventParse(format!(
+                "Stellar event is missing the '{}' topic",
+                STELLAR_VERIFIED_TOPIC
+            )));
+        }
+        if data.len() != 56 {
+            return Err(OrchestrationError::EventParse(format!(
+                "Invalid Stellar event data length: expected 56 bytes, got {}",
+                data.len()
+            )));
+        }
+
+        let mut institutional_pubkey = [0u8; 32];
+        institutional_pubkey.copy_from_slice(&data[..32]);
+        let be_u64 = |range: std::ops::Range<usize>| {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&data[range]);
+            u64::from_be_bytes(buf)
+        };
+
+        Ok(Self {
+            institutional_pubkey,
+            threshold: be_u64(32..40),
+            count: be_u64(40..48),
+            timestamp: be_u64(48..56) as i64,
+        })
+    }
+
+    fn from_evm_log(topics: &[[u8; 32]], data: &[u8]) -> Result<Self, OrchestrationError> {
+        use ethers_core::abi::{decode, ParamType, Token};
+        use ethers_core::utils::keccak256;
+
+        if topics.len() != 2 || topics[0] != keccak256(EVM_VERIFIED_SIGNATURE) {
+            return Err(OrchestrationError::EventParse("EVM log is not a ProofVerified event".into()));
+        }
+
+        let tokens = decode(&[ParamType::Uint(256), ParamType::Uint(256), ParamType::Uint(256)], data)
+            .map_err(|e| OrchestrationError::EventParse(format!("Invalid EVM event data: {}", e)))?;
+        let as_u64 = |token: &Token, field: &str| match token {
+            Token::Uint(value) if value.bits() <= 64 => Ok(value.as_u64()),
+            _ => Err(OrchestrationError::EventParse(format!("EVM event {} out of range", field))),
+        };
+
+        Ok(Self {
+            institutional_pubkey: topics[1],
+            threshold: as_u64(&tokens[0], "threshold")?,
+            count: as_u64(&tokens[1], "count")?,
+            timestamp: as_u64(&tokens[2], "timestamp")? as i64,
+        })
+    }
+
+    fn from_cosmwasm_attributes(attributes: &[(String, String)]) -> Result<Self, OrchestrationError> {
+        let get = |key: &str| {
+            attributes
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+                .ok_or_else(|| OrchestrationError::EventParse(format!("Missing attribute '{}'", key)))
+        };
+        let parse_num = |key: &str| {
+            get(key)?
+                .parse::<u64>()
+                .map_err(|_| OrchestrationError::EventParse(format!("Invalid attribute '{}'", key)))
+        };
+
+        let pubkey_bytes = hex::decode(get("institutional_pubkey")?)
+            .map_err(|_| OrchestrationError::EventParse("Invalid institutional_pubkey hex".into()))?;
+        let institutional_pubkey: [u8; 32] = pubkey_bytes
+            .try_into()
+            .map_err(|_| OrchestrationError::EventParse("institutional_pubkey must be 32 bytes".into()))?;
+
+        Ok(Self {
+            institutional_pubkey,
+            threshold: parse_num("threshold")?,
+            count: parse_num("verification_count")?,
+            timestamp: parse_num("timestamp")? as i64,
+        })
+    }
+}
+
+/// Anchor event discriminator: first 8 bytes of `sha256("event:<name>")`.
+pub fn anchor_event_discriminator(event_name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("event:{}", event_name).as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+/// Source of raw verifier events (an RPC client, indexer, or test mock)
+#[async_trait]
+pub trait ChainEventSource: Send + Sync {
+    /// Fetch the most recent verifier event emitted on `chain`
+    async fn last_event(&self, chain: ChainType) -> Result<RawChainEvent, OrchestrationError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    const PUBKEY: [u8; 32] = [9u8; 32];
+
+    fn expected() -> RwaPublicValues {
+        RwaPublicValues {
+            institutional_pubkey: PUBKEY,
+            threshold: 500_000,
+            max_threshold: crate::rwa::NO_MAX_THRESHOLD,
+            freshness: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_parse_solana_event() {
+        let mut payload = anchor_event_discriminator("ProofVerified").to_vec();
+        payload.extend_from_slice(&PUBKEY);
+        payload.extend_from_slice(&500_000u64.to_le_bytes());
+        payload.extend_from_slice(&3u64.to_le_bytes());
+        payload.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        let raw = RawChainEvent::Solana {
+            logs: vec![
+                "Program log: Instruction: VerifyRwaProof".to_string(),
+                format!("Program data: {}", STANDARD.encode(&payload)),
+            ],
+        };
+        let event = VerifiedEvent::from_raw(&raw).unwrap();
+
+        assert_eq!(event.count, 3);
+        assert_eq!(event.timestamp, 1_700_000_000);
+        assert!(event.matches(&expected()));
+    }
+
+    #[test]
+    fn test_parse_solana_missing_event() {
+        let raw = RawChainEvent::Solana { logs: vec!["Program log: hello".to_string()] };
+        assert!(matches!(VerifiedEvent::from_raw(&raw), Err(OrchestrationError::EventParse(_))));
+    }
+
+    #[test]
+    fn test_parse_stellar_event() {
+        let mut data = PUBKEY.to_vec();
+        data.extend_from_slice(&500_000u64.to_be_bytes());
+        data.extend_from_slice(&7u64.to_be_bytes());
+        data.extend_from_slice(&1_700_000_000u64.to_be_bytes());
+
+        let raw = RawChainEvent::Stellar { topics: vec!["proof_verified".to_string()], data };
+        let event = VerifiedEvent::from_raw(&raw).unwrap();
+
+        assert_eq!(event.count, 7);
+        assert!(event.matches(&expected()));
+    }
+
+    #[test]
+    fn test_parse_evm_event() {
+        use ethers_core::abi::{encode, Token};
+        use ethers_core::types::U256;
+        use ethers_core::utils::keccak256;
+
+        let data = encode(&[
+            Token::Uint(U256::from(500_000u64)),
+            Token::Uint(U256::from(2u64)),
+            Token::Uint(U256::from(1_700_000_000u64)),
+        ]);
+        let raw = RawChainEvent::Evm {
+            topics: vec![keccak256(EVM_VERIFIED_SIGNATURE), PUBKEY],
+            data,
+        };
+        let event = VerifiedEvent::from_raw(&raw).unwrap();
+
+        assert_eq!(event.count, 2);
+        assert!(event.matches(&expected()));
+    }
+
+    #[test]
+    fn test_parse_cosmwasm_event() {
+        let raw = RawChainEvent::Cosmwasm {
+            attributes: vec![
+                ("action".to_string(), "verify_proof".to_string()),
+                ("institutional_pubkey".to_string(), hex::encode(PUBKEY)),
+                ("threshold".to_string(), "500000".to_string()),
+                ("verification_count".to_string(), "1".to_string()),
+                ("timestamp".to_string(), "1700000000".to_string()),
+            ],
+        };
+        let event = VerifiedEvent::from_raw(&raw).unwrap();
+
+        assert_eq!(event.count, 1);
+        assert!(event.matches(&expected()));
+        assert!(!event.matches(&RwaPublicValues { threshold: 1, ..expected() }));
+        // The event carries no upper bound, so a range claim can't be confirmed
+        assert!(!event.matches(&RwaPublicValues { max_threshold: 1_000_000, ..expected() }));
+    }
+}
@@ -4,8 +4,11 @@
 //! Supports PDF text, JSON, CSV, and API responses.
 
 use crate::rwa::RwaClaim;
+use async_trait::async_trait;
+use ed25519_dalek::{Signer, SigningKey};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Data source types supported by the extractor
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +45,149 @@ pub struct ExtractionResult {
     pub metadata: HashMap<String, String>,
 }
 
+impl ExtractionResult {
+    /// Turn the extracted balance into a claim signed by the institution.
+    ///
+    /// `claim` on an `ExtractionResult` carries placeholder key material and is
+    /// only useful for inspection. This fills in the real institutional pubkey
+    /// from `signer` and signs the extracted balance, producing a claim that
+    /// passes `RwaClaim::verify_signature` (and therefore the guest's check).
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - The institution's Ed25519 signing key
+    /// * `threshold` - Minimum required balance for compliance
+    /// * `timestamp` - Attestation time (Unix seconds)
+    /// * `nonce` - Fresh random nonce for replay protection
+    pub fn into_signed_claim(
+        &self,
+        signer: &SigningKey,
+        threshold: u64,
+        timestamp: u64,
+        nonce: [u8; 16],
+    ) -> RwaClaim {
+        let mut claim = RwaClaim::new(
+            signer.verifying_key().to_bytes(),
+            self.claim.balance,
+            threshold,
+            timestamp,
+            nonce,
+            [0u8; 64],
+        );
+        claim.signature = signer.sign(&claim.message_to_sign()).to_bytes();
+        claim
+    }
+}
+
+/// Minimum per-field confidence before a targeted re-extraction is attempted
+pub const REQUIRED_FIELD_CONFIDENCE: f32 = 0.5;
+
+/// Confidence assigned to a field recovered by the secondary (retry) pass
+const RETRY_FIELD_CONFIDENCE: f32 = 0.7;
+
+/// Line introducing the document in the extraction prompt
+const INPUT_MARKER: &str = "INPUT DATA:";
+
+/// Line following the document in the extraction prompt
+const OUTPUT_MARKER: &str = "OUTPUT FORMAT";
+
+/// Language model used for structured extraction
+///
+/// `complete` receives the full extraction prompt and must answer with a JSON
+/// object containing `balance` (cents), `institution` and `date`, using
+/// `null` for anything it can't find. Text around the object (e.g. Markdown
+/// code fences) is ignored.
+#[async_trait]
+pub trait LlmClient: Send + Sync {
+    /// Complete `prompt` and return the model's raw response
+    async fn complete(&self, prompt: &str) -> Result<String, ExtractionError>;
+}
+
+/// Offline `LlmClient` answering with regex matches over the prompt's input
+///
+/// Used when no model is configured. Recognizes `Balance: $1,234.56`-style
+/// amounts, a fixed list of bank names and numeric dates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RegexLlmClient;
+
+#[async_trait]
+impl LlmClient for RegexLlmClient {
+    async fn complete(&self, prompt: &str) -> Result<String, ExtractionError> {
+        let text = prompt_input(prompt);
+        let response = serde_json::json!({
+            "balance": self.extract_balance(text)?,
+            "institution": self.extract_institution(text),
+            "date": self.extract_date(text),
+        });
+        Ok(response.to_string())
+    }
+}
+
+impl RegexLlmClient {
+    /// Extract balance from text (simple regex)
+    fn extract_balance(&self, text: &str) -> Result<u64, ExtractionError> {
+        // Look for patterns like "$1,234.56" or "Balance: 1234.56"
+        let re = regex::Regex::new(r"(?i)(?:balance|total|amount)[\s:$]*([0-9,]+\.?\d{0,2})")
+            .unwrap();
+
+        if let Some(caps) = re.captures(text) {
+            let balance_str = caps.get(1)
+                .ok_or(ExtractionError::BalanceNotFound)?
+                .as_str()
+                .replace(",", "");
+            
+            let balance_dollars: f64 = balance_str.parse()
+                .map_err(|_| ExtractionError::InvalidBalance)?;
+            
+            // Convert to cents
+            Ok((balance_dollars * 100.0) as u64)
+        } else {
+            Err(ExtractionError::BalanceNotFound)
+        }
+    }
+
+    /// Extract institution name
+    fn extract_institution(&self, text: &str) -> Option<String> {
+        // Look for known bank names
+        let banks = ["Chase", "Bank of America", "Wells Fargo", "Citi", "Goldman Sachs"];
+        
+        for bank in &banks {
+            if text.contains(bank) {
+                return Some(bank.to_string());
+            }
+        }
+        
+        None
+    }
+
+    /// Extract date
+    fn extract_date(&self, text: &str) -> Option<String> {
+        let re = regex::Regex::new(r"\d{4}-\d{2}-\d{2}|\d{2}/\d{2}/\d{4}")
+            .unwrap();
+        
+        re.find(text).map(|m| m.as_str().to_string())
+    }
+}
+
+/// The document embedded in an extraction prompt, or the whole prompt if the
+/// markers are missing
+fn prompt_input(prompt: &str) -> &str {
+    let Some(start) = prompt.rfind(INPUT_MARKER) else {
+        return prompt;
+    };
+    let input = &prompt[start + INPUT_MARKER.len()..];
+    let end = input.rfind(OUTPUT_MARKER).unwrap_or(input.len());
+    input[..end].trim()
+}
+
+/// Fields a model returns for an extraction prompt
+#[derive(Debug, Deserialize)]
+struct LlmResponse {
+    balance: Option<u64>,
+    institution: Option<String>,
+    date: Option<String>,
+}
+
 /// Structured extractor using LLM for parsing
 pub struct StructuredExtractor {
     /// Prompt template for extraction
@@ -49,26 +195,68 @@ pub struct StructuredExtractor {
     
     /// Few-shot examples for better accuracy
     examples: Vec<(String, String)>,
+
+    /// Model answering extraction prompts
+    llm: Arc<dyn LlmClient>,
+
+    /// Column mapping for `DataSource::Csv`; CSV goes to the LLM without one
+    csv_schema: Option<CsvSchema>,
 }
 
 impl StructuredExtractor {
-    /// Create a new structured extractor
+    /// Create a new structured extractor backed by `RegexLlmClient`
     pub fn new() -> Self {
+        Self::with_llm_client(Arc::new(RegexLlmClient))
+    }
+
+    /// Create a structured extractor that prompts `llm`
+    pub fn with_llm_client(llm: Arc<dyn LlmClient>) -> Self {
         Self {
             prompt_template: Self::default_prompt_template(),
             examples: Self::default_examples(),
+            llm,
+            csv_schema: None,
         }
     }
 
+    /// Read `DataSource::Csv` inputs with `schema` instead of prompting the LLM
+    pub fn with_csv_schema(mut self, schema: CsvSchema) -> Self {
+        self.csv_schema = Some(schema);
+        self
+    }
+
     /// Extract RWA claim from data source
     ///
     /// This method:
     /// 1. Sanitizes the input data
     /// 2. Builds a prompt with schema
-    /// 3. Calls LLM for structured extraction (placeholder)
+    /// 3. Calls the `LlmClient` for structured extraction
     /// 4. Validates the output
     /// 5. Returns with confidence score
-    pub fn extract(&self, source: DataSource) -> Result<ExtractionResult, ExtractionError> {
+    pub async fn extract(&self, source: DataSource) -> Result<ExtractionResult, ExtractionError> {
+        self.extract_with_min_confidence(source, 0.0).await
+    }
+
+    /// Extract an RWA claim, rejecting results with confidence below `min`
+    ///
+    /// Automated pipelines should use this so low-confidence extractions are
+    /// never proven.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExtractionError::LowConfidence` if the computed confidence is
+    /// below `min`, plus the errors of `extract`
+    pub async fn extract_with_min_confidence(
+        &self,
+        source: DataSource,
+        min: f32,
+    ) -> Result<ExtractionResult, ExtractionError> {
+        // Mapped CSV columns are read directly, without the LLM
+        let schema = match &source {
+            DataSource::Csv(_) => self.csv_schema.as_ref(),
+            _ => None,
+        };
+
         // Convert source to text
         let raw_text = match source {
             DataSource::Text(text) => text,
@@ -78,20 +266,27 @@ impl StructuredExtractor {
             DataSource::Api { response, .. } => response,
         };
 
-        // Sanitize input (remove PII)
-        let sanitized = self.sanitize_input(&raw_text);
-
         // Hash the source data
         let source_hash = self.hash_source(&raw_text);
 
-        // Extract structured data (placeholder - would call LLM here)
-        let extracted_data = self.extract_with_llm(&sanitized)?;
+        // Extract structured data
+        let extracted_data = match schema {
+            Some(schema) => self.extract_csv(&raw_text, schema)?,
+            None => {
+                // Sanitize input (remove PII)
+                let sanitized = self.sanitize_input(&raw_text);
+                self.extract_with_llm(&sanitized).await?
+            }
+        };
 
         // Parse into RwaClaim
         let claim = self.parse_claim(&extracted_data)?;
 
         // Calculate confidence
         let confidence = self.calculate_confidence(&extracted_data);
+        if confidence < min {
+            return Err(ExtractionError::LowConfidence { got: confidence, required: min });
+        }
 
         // Collect warnings
         let warnings = self.collect_warnings(&extracted_data);
@@ -138,64 +333,147 @@ impl StructuredExtractor {
         hasher.finalize().into()
     }
 
-    /// Extract structured data using LLM (placeholder)
-    fn extract_with_llm(&self, sanitized_text: &str) -> Result<ExtractedData, ExtractionError> {
-        // TODO: Integrate actual LLM SDK
-        // For now, use simple regex-based extraction as placeholder
-        
-        let balance = self.extract_balance(sanitized_text)?;
-        let institution = self.extract_institution(sanitized_text);
-        let date = self.extract_date(sanitized_text);
+    /// Read the first data row of a CSV export using `schema`'s columns
+    fn extract_csv(&self, csv_text: &str, schema: &CsvSchema) -> Result<ExtractedData, ExtractionError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(csv_text.as_bytes());
+
+        let headers = reader
+            .headers()
+            .map_err(|e| ExtractionError::InvalidInput(format!("Invalid CSV header: {}", e)))?
+            .clone();
+        let column = |name: &str| {
+            headers
+                .iter()
+                .position(|h| h == name)
+                .ok_or_else(|| ExtractionError::InvalidInput(format!("CSV has no column '{}'", name)))
+        };
+        let (balance_col, institution_col, date_col) = (
+            column(&schema.balance_column)?,
+            column(&schema.institution_column)?,
+            column(&schema.date_column)?,
+        );
+
+        let row = reader
+            .records()
+            .next()
+            .ok_or(ExtractionError::BalanceNotFound)?
+            .map_err(|e| ExtractionError::InvalidInput(format!("Invalid CSV row: {}", e)))?;
+        let cell = |index: usize| row.get(index).filter(|v| !v.is_empty()).map(str::to_string);
+
+        let balance = cell(balance_col)
+            .ok_or(ExtractionError::BalanceNotFound)
+            .and_then(|v| parse_cents(&v))?;
+
+        let mut metadata = HashMap::new();
+        metadata.insert("extraction_method".to_string(), "csv_schema".to_string());
 
         Ok(ExtractedData {
             balance,
-            institution,
-            date,
-            metadata: HashMap::new(),
+            institution: cell(institution_col),
+            date: cell(date_col),
+            metadata,
         })
     }
 
-    /// Extract balance from text (simple regex)
-    fn extract_balance(&self, text: &str) -> Result<u64, ExtractionError> {
-        // Look for patterns like "$1,234.56" or "Balance: 1234.56"
-        let re = regex::Regex::new(r"(?i)(?:balance|total|amount)[\s:$]*([0-9,]+\.?\d{0,2})")
-            .unwrap();
+    /// Build the extraction prompt for `input` from the template and examples
+    fn build_prompt(&self, input: &str) -> String {
+        let examples = self
+            .examples
+            .iter()
+            .map(|(example_input, example_output)| format!("Input:\n{}\nOutput:\n{}", example_input, example_output))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        self.prompt_template
+            .replace("{examples}", &examples)
+            .replace("{input}", input)
+    }
 
-        if let Some(caps) = re.captures(text) {
-            let balance_str = caps.get(1)
-                .ok_or(ExtractionError::BalanceNotFound)?
-                .as_str()
-                .replace(",", "");
-            
-            let balance_dollars: f64 = balance_str.parse()
-                .map_err(|_| ExtractionError::InvalidBalance)?;
-            
-            // Convert to cents
-            Ok((balance_dollars * 100.0) as u64)
-        } else {
-            Err(ExtractionError::BalanceNotFound)
-        }
+    /// Extract structured data using the LLM client
+    async fn extract_with_llm(&self, sanitized_text: &str) -> Result<ExtractedData, ExtractionError> {
+        let response = self.llm.complete(&self.build_prompt(sanitized_text)).await?;
+        let parsed = parse_llm_response(&response)?;
+
+        let balance = parsed.balance.ok_or(ExtractionError::BalanceNotFound)?;
+        let mut metadata = HashMap::new();
+        let mut attempts = 0u32;
+
+        // Re-extract any required field below its confidence threshold,
+        // targeting only that field
+        let institution = self.extract_field_with_retry(
+            "institution",
+            sanitized_text,
+            parsed.institution,
+            Self::reextract_institution,
+            &mut attempts,
+            &mut metadata,
+        );
+        let date = self.extract_field_with_retry(
+            "date",
+            sanitized_text,
+            parsed.date,
+            Self::reextract_date,
+            &mut attempts,
+            &mut metadata,
+        );
+        metadata.insert("reextraction_attempts".to_string(), attempts.to_string());
+
+        Ok(ExtractedData {
+            balance,
+            institution,
+            date,
+            metadata,
+        })
     }
 
-    /// Extract institution name
-    fn extract_institution(&self, text: &str) -> Option<String> {
-        // Look for known bank names
-        let banks = ["Chase", "Bank of America", "Wells Fargo", "Citi", "Goldman Sachs"];
-        
-        for bank in &banks {
-            if text.contains(bank) {
-                return Some(bank.to_string());
+    /// Take the model's value for a field, retrying with a targeted
+    /// secondary extractor if the field's confidence is below
+    /// `REQUIRED_FIELD_CONFIDENCE`. Records `<field>_confidence` in `metadata`.
+    fn extract_field_with_retry(
+        &self,
+        field: &str,
+        text: &str,
+        primary: Option<String>,
+        retry: fn(&Self, &str) -> Option<String>,
+        attempts: &mut u32,
+        metadata: &mut HashMap<String, String>,
+    ) -> Option<String> {
+        let mut value = primary.filter(|v| !v.trim().is_empty());
+        let mut confidence = if value.is_some() { 1.0 } else { 0.0 };
+
+        if confidence < REQUIRED_FIELD_CONFIDENCE {
+            *attempts += 1;
+            value = retry(self, text);
+            if value.is_some() {
+                confidence = RETRY_FIELD_CONFIDENCE;
             }
         }
-        
-        None
+
+        metadata.insert(format!("{}_confidence", field), confidence.to_string());
+        value
     }
 
-    /// Extract date
-    fn extract_date(&self, text: &str) -> Option<String> {
-        let re = regex::Regex::new(r"\d{4}-\d{2}-\d{2}|\d{2}/\d{2}/\d{4}")
-            .unwrap();
-        
+    /// Secondary institution extraction: any line naming a bank, credit union, or trust
+    fn reextract_institution(&self, text: &str) -> Option<String> {
+        let re = regex::Regex::new(
+            r"(?m)^[ \t]*([A-Z][A-Za-z&.' ]*?\b(?:Bank|Credit Union|Trust|Financial)(?: N\.A\.)?)\b",
+        )
+        .unwrap();
+
+        re.captures(text)
+            .and_then(|caps| caps.get(1))
+            .map(|m| m.as_str().trim().to_string())
+    }
+
+    /// Secondary date extraction: written-out dates like "January 15, 2024"
+    fn reextract_date(&self, text: &str) -> Option<String> {
+        let re = regex::Regex::new(
+            r"(?i)\b(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]*\.? \d{1,2}, \d{4}",
+        )
+        .unwrap();
+
         re.find(text).map(|m| m.as_str().to_string())
     }
 
@@ -208,6 +486,8 @@ impl StructuredExtractor {
             [1u8; 32],  // Placeholder institutional pubkey
             data.balance,
             data.balance / 2,  // Placeholder threshold (50% of balance)
+            0,  // Placeholder timestamp
+            [0u8; 16],  // Placeholder nonce
             [2u8; 64],  // Placeholder signature
         ))
     }
@@ -254,6 +534,9 @@ REQUIRED FIELDS:
 - institution: Name of the financial institution
 - date: Statement date (YYYY-MM-DD)
 
+EXAMPLES:
+{examples}
+
 INPUT DATA:
 {input}
 
@@ -288,6 +571,64 @@ impl Default for StructuredExtractor {
     }
 }
 
+/// Columns of a CSV statement export holding each extracted field
+///
+/// Names are matched exactly against the header row.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CsvSchema {
+    /// Column with the balance in dollars (e.g. `50000.00` or `$50,000.00`)
+    pub balance_column: String,
+
+    /// Column with the institution name
+    pub institution_column: String,
+
+    /// Column with the statement date
+    pub date_column: String,
+}
+
+impl CsvSchema {
+    /// Map the balance, institution and date columns by header name
+    pub fn new(
+        balance_column: impl Into<String>,
+        institution_column: impl Into<String>,
+        date_column: impl Into<String>,
+    ) -> Self {
+        Self {
+            balance_column: balance_column.into(),
+            institution_column: institution_column.into(),
+            date_column: date_column.into(),
+        }
+    }
+}
+
+/// Parse a dollar amount (`1234.5`, `$1,234.56`) into cents without rounding
+fn parse_cents(amount: &str) -> Result<u64, ExtractionError> {
+    let digits = amount.trim().trim_start_matches('$').replace(',', "");
+    let (dollars, cents) = digits.split_once('.').unwrap_or((&digits, ""));
+
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if dollars.is_empty() || !all_digits(dollars) || cents.len() > 2 || !all_digits(cents) {
+        return Err(ExtractionError::InvalidBalance);
+    }
+
+    let dollars: u64 = dollars.parse().map_err(|_| ExtractionError::InvalidBalance)?;
+    let cents: u64 = format!("{:0<2}", cents).parse().map_err(|_| ExtractionError::InvalidBalance)?;
+    dollars
+        .checked_mul(100)
+        .and_then(|d| d.checked_add(cents))
+        .ok_or(ExtractionError::InvalidBalance)
+}
+
+/// Parse the JSON object in a model response
+fn parse_llm_response(response: &str) -> Result<LlmResponse, ExtractionError> {
+    let json = match (response.find('{'), response.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &response[start..=end],
+        _ => return Err(ExtractionError::LlmError("Response contains no JSON object".into())),
+    };
+    serde_json::from_str(json)
+        .map_err(|e| ExtractionError::LlmError(format!("Invalid JSON response: {}", e)))
+}
+
 /// Intermediate extracted data
 #[derive(Debug)]
 struct ExtractedData {
@@ -311,6 +652,9 @@ pub enum ExtractionError {
     
     #[error("LLM extraction failed: {0}")]
     LlmError(String),
+
+    #[error("Extraction confidence {got} is below the required {required}")]
+    LowConfidence { got: f32, required: f32 },
 }
 
 #[cfg(test)]
@@ -328,21 +672,194 @@ mod tests {
 
     #[test]
     fn test_extract_balance() {
-        let extractor = StructuredExtractor::new();
         let text = "Account Balance: $1,234.56";
-        let balance = extractor.extract_balance(text).unwrap();
+        let balance = RegexLlmClient.extract_balance(text).unwrap();
         assert_eq!(balance, 123456);
     }
 
-    #[test]
-    fn test_extract_from_text() {
+    #[tokio::test]
+    async fn test_extract_from_text() {
         let extractor = StructuredExtractor::new();
         let source = DataSource::Text(
             "Chase Bank\nAccount Balance: $50,000.00\nDate: 2024-01-15".to_string()
         );
         
-        let result = extractor.extract(source).unwrap();
+        let result = extractor.extract(source).await.unwrap();
         assert_eq!(result.claim.balance, 5000000);
         assert!(result.confidence > 0.7);
     }
+
+    #[tokio::test]
+    async fn test_retry_recovers_missing_institution() {
+        let extractor = StructuredExtractor::new();
+        let text = "First Republic Bank\nAccount Balance: $10,000.00\nStatement date: March 3, 2024";
+
+        // The primary pass misses both the institution and the date
+        assert!(RegexLlmClient.extract_institution(text).is_none());
+        assert!(RegexLlmClient.extract_date(text).is_none());
+
+        let result = extractor.extract(DataSource::Text(text.to_string())).await.unwrap();
+
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.metadata["reextraction_attempts"], "2");
+        assert_eq!(result.metadata["institution_confidence"], RETRY_FIELD_CONFIDENCE.to_string());
+        let data = extractor.extract_with_llm(text).await.unwrap();
+        assert_eq!(data.institution.as_deref(), Some("First Republic Bank"));
+        assert_eq!(data.date.as_deref(), Some("March 3, 2024"));
+    }
+
+    #[tokio::test]
+    async fn test_low_confidence_rejected() {
+        let extractor = StructuredExtractor::new();
+        let text = "Account Balance: $50,000.00";
+
+        let result = extractor.extract_with_min_confidence(DataSource::Text(text.to_string()), 0.75).await;
+        assert!(matches!(
+            result,
+            Err(ExtractionError::LowConfidence { got, required }) if got < 0.75 && required == 0.75
+        ));
+
+        // Ungated extraction still returns the claim
+        let result = extractor.extract(DataSource::Text(text.to_string())).await.unwrap();
+        assert_eq!(result.warnings.len(), 2);
+
+        let complete = "Chase Bank\nAccount Balance: $50,000.00\nDate: 2024-01-15";
+        assert!(extractor.extract_with_min_confidence(DataSource::Text(complete.to_string()), 0.75).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_when_fields_found() {
+        let extractor = StructuredExtractor::new();
+        let source = DataSource::Text(
+            "Chase Bank\nAccount Balance: $50,000.00\nDate: 2024-01-15".to_string()
+        );
+        let result = extractor.extract(source).await.unwrap();
+        assert_eq!(result.metadata["reextraction_attempts"], "0");
+    }
+
+    /// Returns a canned response and records the prompt it was given
+    struct MockLlm {
+        response: String,
+        prompt: std::sync::Mutex<Option<String>>,
+    }
+
+    impl MockLlm {
+        fn new(response: &str) -> Arc<Self> {
+            Arc::new(Self { response: response.to_string(), prompt: std::sync::Mutex::new(None) })
+        }
+    }
+
+    #[async_trait]
+    impl LlmClient for MockLlm {
+        async fn complete(&self, prompt: &str) -> Result<String, ExtractionError> {
+            *self.prompt.lock().unwrap() = Some(prompt.to_string());
+            Ok(self.response.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_llm_response_is_used() {
+        let llm = MockLlm::new(r#"{"balance":123456,"institution":"Chase","date":"2024-01-15"}"#);
+        let extractor = StructuredExtractor::with_llm_client(llm.clone());
+
+        let result = extractor
+            .extract(DataSource::Text("statement text with no figures".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(result.claim.balance, 123456);
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.confidence, 1.0);
+
+        // The prompt carries the template, the few-shot examples and the input
+        let prompt = llm.prompt.lock().unwrap().clone().unwrap();
+        assert!(prompt.contains("REQUIRED FIELDS"));
+        assert!(prompt.contains("Chase Bank Statement"));
+        assert_eq!(prompt_input(&prompt), "statement text with no figures");
+    }
+
+    #[tokio::test]
+    async fn test_malformed_llm_response() {
+        let fenced = MockLlm::new("```json\n{\"balance\":500,\"institution\":null,\"date\":null}\n```");
+        let result = StructuredExtractor::with_llm_client(fenced)
+            .extract(DataSource::Text("Balance due".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(result.claim.balance, 500);
+
+        let missing_balance = MockLlm::new(r#"{"balance":null,"institution":"Chase","date":null}"#);
+        let result = StructuredExtractor::with_llm_client(missing_balance)
+            .extract(DataSource::Text("x".to_string()))
+            .await;
+        assert!(matches!(result, Err(ExtractionError::BalanceNotFound)));
+
+        let prose = MockLlm::new("I could not find a balance.");
+        let result = StructuredExtractor::with_llm_client(prose)
+            .extract(DataSource::Text("x".to_string()))
+            .await;
+        assert!(matches!(result, Err(ExtractionError::LlmError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_csv_schema_extraction() {
+        let llm = MockLlm::new("{}");
+        let extractor = StructuredExtractor::with_llm_client(llm.clone())
+            .with_csv_schema(CsvSchema::new("account_balance", "bank_name", "statement_date"));
+        let csv = "account_balance,bank_name,statement_date\n\"$50,000.07\",First Republic Bank,2024-01-15\n";
+
+        let result = extractor.extract(DataSource::Csv(csv.to_string())).await.unwrap();
+        assert_eq!(result.claim.balance, 5_000_007);
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.metadata["extraction_method"], "csv_schema");
+        // The LLM is never consulted for mapped CSV
+        assert!(llm.prompt.lock().unwrap().is_none());
+
+        // Empty cells become missing fields
+        let csv = "statement_date,account_balance,bank_name\n,1234.5,\n";
+        let result = extractor.extract(DataSource::Csv(csv.to_string())).await.unwrap();
+        assert_eq!(result.claim.balance, 123450);
+        assert_eq!(result.warnings.len(), 2);
+
+        let csv = "balance,bank_name,statement_date\n100,Chase,2024-01-15\n";
+        let result = extractor.extract(DataSource::Csv(csv.to_string())).await;
+        assert!(matches!(result, Err(ExtractionError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn test_csv_without_schema_uses_llm() {
+        let extractor = StructuredExtractor::new();
+        let csv = "Chase Bank,Balance: $10.00,2024-01-15";
+        let result = extractor.extract(DataSource::Csv(csv.to_string())).await.unwrap();
+        assert_eq!(result.claim.balance, 1000);
+    }
+
+    #[test]
+    fn test_parse_cents() {
+        assert_eq!(parse_cents("$1,234.56").unwrap(), 123456);
+        assert_eq!(parse_cents("0.1").unwrap(), 10);
+        assert_eq!(parse_cents("42").unwrap(), 4200);
+        for bad in ["", "12.345", "-5", "1e3", "$", ".5"] {
+            assert!(parse_cents(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_into_signed_claim() {
+        let extractor = StructuredExtractor::new();
+        let source = DataSource::Text(
+            "Chase Bank\nAccount Balance: $50,000.00\nDate: 2024-01-15".to_string()
+        );
+        let result = extractor.extract(source).await.unwrap();
+        let signer = SigningKey::from_bytes(&[7u8; 32]);
+
+        let claim = result.into_signed_claim(&signer, 1_000_000, 1_735_128_100, [3u8; 16]);
+
+        assert_eq!(claim.balance, 5000000);
+        assert_eq!(claim.threshold, 1_000_000);
+        assert_eq!(claim.timestamp, 1_735_128_100);
+        assert_eq!(claim.nonce, [3u8; 16]);
+        assert_eq!(claim.institutional_pubkey, signer.verifying_key().to_bytes());
+        assert!(claim.verify_signature());
+        // The unsigned inspection claim is left untouched
+        assert!(!result.claim.verify_signature());
+    }
 }
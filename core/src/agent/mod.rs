@@ -6,7 +6,13 @@
 pub mod extractor;
 pub mod validator;
 pub mod orchestrator;
+pub mod events;
+pub mod preflight;
+pub mod solana;
 
-pub use extractor::{StructuredExtractor, ExtractionResult, DataSource};
+pub use extractor::{StructuredExtractor, ExtractionResult, DataSource, CsvSchema, LlmClient, RegexLlmClient};
 pub use validator::{SchemaValidator, ValidationResult};
-pub use orchestrator::{ChainOrchestrator, SubmissionResult};
+pub use orchestrator::{ChainCost, ChainOrchestrator, OrchestratorConfig, SubmissionResult};
+pub use events::{ChainEventSource, RawChainEvent, VerifiedEvent};
+pub use preflight::{VerifierStateSource, VerifierStatus};
+pub use solana::{RpcSolanaClient, SolanaAccountMeta, SolanaConfig, SolanaRpc, SolanaSubmission};
@@ -2,8 +2,45 @@
 //!
 //! Multi-chain proof submission and verification orchestration.
 
-use crate::{ChainType, ProofReceipt};
+use super::events::{ChainEventSource, VerifiedEvent};
+use super::preflight::VerifierStateSource;
+use super::solana::{SolanaConfig, SolanaRpc};
+use crate::logging::{AgentAction, AuditContext, ZkAuditTrail};
+use crate::{ChainType, PrivacyEngine, ProofReceipt};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Decision-logic identifier recorded for submissions in the audit trail
+const SUBMIT_DECISION_LOGIC: &[u8] = b"ChainOrchestrator::submit";
+
+/// Chain-native cost of a submitted transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChainCost {
+    /// Solana compute units consumed
+    Solana { compute_units: u64 },
+
+    /// EVM gas used and effective gas price (wei)
+    Evm { gas: u64, gas_price: u128 },
+
+    /// Stellar resource fee (stroops)
+    Stellar { resource_fee: u64 },
+
+    /// CosmWasm gas requested and consumed
+    Cosmwasm { gas_wanted: u64, gas_used: u64 },
+}
+
+impl ChainCost {
+    /// The chain's primary cost figure, in its native unit
+    pub fn units(&self) -> u64 {
+        match *self {
+            ChainCost::Solana { compute_units } => compute_units,
+            ChainCost::Evm { gas, .. } => gas,
+            ChainCost::Stellar { resource_fee } => resource_fee,
+            ChainCost::Cosmwasm { gas_used, .. } => gas_used,
+        }
+    }
+}
 
 /// Result of proof submission
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,56 +48,270 @@ pub struct SubmissionResult {
     pub transaction_hash: String,
     pub verification_status: bool,
     pub explorer_url: String,
+
+    /// Chain-specific cost breakdown
+    pub cost: ChainCost,
+
+    /// Primary cost figure in the chain's native unit (same as `cost.units()`)
+    #[deprecated(note = "use `cost`, which keeps the chain-specific breakdown")]
     pub gas_used: Option<u64>,
 }
 
+impl SubmissionResult {
+    /// Build a result, deriving the legacy `gas_used` from `cost`
+    #[allow(deprecated)]
+    pub fn new(
+        transaction_hash: impl Into<String>,
+        verification_status: bool,
+        explorer_url: impl Into<String>,
+        cost: ChainCost,
+    ) -> Self {
+        Self {
+            transaction_hash: transaction_hash.into(),
+            verification_status,
+            explorer_url: explorer_url.into(),
+            cost,
+            gas_used: Some(cost.units()),
+        }
+    }
+}
+
+/// Orchestrator configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrchestratorConfig {
+    /// Verify proofs off-chain with the configured engine before submitting
+    pub pre_verify: bool,
+
+    /// Extra attempts when local verification hits a transient error (e.g. a
+    /// prover backend is briefly unreachable, see `ErrorKind::is_transient`).
+    /// A proof that verifies as invalid is never retried.
+    #[serde(default)]
+    pub verify_retries: u32,
+}
+
 /// Chain orchestrator for multi-chain submissions
-pub struct ChainOrchestrator;
+#[derive(Default)]
+pub struct ChainOrchestrator {
+    config: OrchestratorConfig,
+    engine: Option<Arc<dyn PrivacyEngine + Send + Sync>>,
+    verifier_state: Option<Arc<dyn VerifierStateSource>>,
+    solana: Option<(SolanaConfig, Arc<dyn SolanaRpc>)>,
+}
 
 impl ChainOrchestrator {
+    /// Create an orchestrator without a local verification engine
+    pub fn new(config: OrchestratorConfig) -> Self {
+        Self { config, engine: None, verifier_state: None, solana: None }
+    }
+
+    /// Create an orchestrator that can verify proofs locally before submitting
+    pub fn new_with_engine(
+        config: OrchestratorConfig,
+        engine: Arc<dyn PrivacyEngine + Send + Sync>,
+    ) -> Self {
+        Self { config, engine: Some(engine), verifier_state: None, solana: None }
+    }
+
+    /// Use `source` to read on-chain verifier state for `preflight`
+    pub fn with_verifier_state(mut self, source: Arc<dyn VerifierStateSource>) -> Self {
+        self.verifier_state = Some(source);
+        self
+    }
+
+    /// Submit Solana proofs to `config.program_id` through `rpc`
+    ///
+    /// Without this, Solana submissions fail with `SubmissionFailed`.
+    pub fn with_solana(mut self, config: SolanaConfig, rpc: Arc<dyn SolanaRpc>) -> Self {
+        self.solana = Some((config, rpc));
+        self
+    }
+
+    /// Check that `chain`'s verifier is initialized with the expected vkey
+    ///
+    /// Call before `submit` so a proof that would fail on-chain is rejected
+    /// before paying fees.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VerifierNotInitialized` or `VkeyMismatch`, or
+    /// `VerificationFailed` if no verifier state source is configured
+    pub async fn preflight(
+        &self,
+        chain: ChainType,
+        expected_vkey_hash: [u8; 32],
+    ) -> Result<(), OrchestrationError> {
+        let source = self.verifier_state.as_ref().ok_or_else(|| {
+            OrchestrationError::VerificationFailed("No verifier state source configured".into())
+        })?;
+
+        let status = source.verifier_status(chain).await?;
+        let stored = match status.vkey_hash {
+            Some(hash) if status.initialized => hash,
+            _ => return Err(OrchestrationError::VerifierNotInitialized(chain)),
+        };
+
+        if stored != expected_vkey_hash {
+            return Err(OrchestrationError::VkeyMismatch {
+                chain,
+                expected: hex::encode(expected_vkey_hash),
+                actual: hex::encode(stored),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Submit a proof, verifying it locally first when `pre_verify` is set
+    pub async fn submit(
+        &self,
+        proof: ProofReceipt,
+        chain: ChainType,
+    ) -> Result<SubmissionResult, OrchestrationError> {
+        self.submit_with_audit(proof, chain, None).await
+    }
+
+    /// Submit a proof and, on success, append a `SubmitToChain` entry to `audit`
+    ///
+    /// The entry hashes the proof bytes as input and the transaction hash as
+    /// output, and records the chain and submission duration in its context.
+    pub async fn submit_with_audit(
+        &self,
+        proof: ProofReceipt,
+        chain: ChainType,
+        audit: Option<&mut ZkAuditTrail>,
+    ) -> Result<SubmissionResult, OrchestrationError> {
+        if self.config.pre_verify {
+            self.verify_locally_before_submit(&proof)?;
+        }
+
+        let started = Instant::now();
+        let proof_bytes = audit.is_some().then(|| proof.proof.clone());
+        let result = self.submit_proof(proof, chain).await?;
+
+        if let (Some(trail), Some(input)) = (audit, proof_bytes) {
+            trail.add_entry_with_context(
+                AgentAction::SubmitToChain,
+                &input,
+                result.transaction_hash.as_bytes(),
+                SUBMIT_DECISION_LOGIC,
+                if result.verification_status { 1.0 } else { 0.0 },
+                AuditContext {
+                    chain: Some(chain),
+                    duration_ms: Some(started.elapsed().as_millis() as u64),
+                },
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Verify a proof off-chain with the configured engine
+    ///
+    /// Catches receipts corrupted in transit before paying to broadcast them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrchestrationError::ProofInvalid` if the proof doesn't verify,
+    /// or `OrchestrationError::VerificationFailed` if no engine is configured
+    pub fn verify_locally_before_submit(&self, proof: &ProofReceipt) -> Result<(), OrchestrationError> {
+        let engine = self.engine.as_ref().ok_or_else(|| {
+            OrchestrationError::VerificationFailed("No engine configured for local verification".into())
+        })?;
+
+        let mut attempt = 0;
+        loop {
+            match engine.verify(proof) {
+                Ok(true) => return Ok(()),
+                Ok(false) => {
+                    return Err(OrchestrationError::ProofInvalid("Proof failed local verification".into()))
+                }
+                Err(e) if e.kind().is_transient() && attempt < self.config.verify_retries => attempt += 1,
+                Err(e) => return Err(OrchestrationError::ProofInvalid(e.to_string())),
+            }
+        }
+    }
+
     /// Submit proof to specified chain
+    ///
+    /// The chain calldata is derived once via `ProofReceipt::to_chain_calldata`
+    /// and handed to the chain client, so receipts that can't be verified on the
+    /// target chain are rejected before any RPC call is made.
     pub async fn submit_proof(
+        &self,
         proof: ProofReceipt,
         chain: ChainType,
     ) -> Result<SubmissionResult, OrchestrationError> {
+        let calldata = proof
+            .to_chain_calldata(chain)
+            .map_err(|e| OrchestrationError::Encoding(e.to_string()))?;
+
         match chain {
-            ChainType::Solana => Self::submit_to_solana(proof).await,
-            ChainType::Stellar => Self::submit_to_stellar(proof).await,
-            ChainType::Evm => Self::submit_to_evm(proof).await,
+            ChainType::Solana => self.submit_to_solana(calldata).await,
+            ChainType::Stellar => Self::submit_to_stellar(calldata).await,
+            ChainType::Evm => Self::submit_to_evm(calldata).await,
+            ChainType::Cosmwasm => Self::submit_to_cosmwasm(calldata).await,
         }
     }
 
-    /// Submit to Solana
-    async fn submit_to_solana(proof: ProofReceipt) -> Result<SubmissionResult, OrchestrationError> {
-        // Placeholder - would use Solana SDK
-        Ok(SubmissionResult {
-            transaction_hash: "solana_tx_placeholder".to_string(),
-            verification_status: true,
-            explorer_url: "https://explorer.solana.com/tx/placeholder".to_string(),
-            gas_used: Some(250000),
-        })
+    /// Fetch and parse the most recent verifier event on `chain`
+    ///
+    /// Use `VerifiedEvent::matches` on the result to confirm the verifier
+    /// committed the same `RwaPublicValues` that were proven off-chain.
+    pub async fn fetch_last_event(
+        source: &dyn ChainEventSource,
+        chain: ChainType,
+    ) -> Result<VerifiedEvent, OrchestrationError> {
+        let raw = source.last_event(chain).await?;
+        VerifiedEvent::from_raw(&raw)
+    }
+
+    /// Submit to Solana as a `verify_rwa_proof` instruction
+    async fn submit_to_solana(&self, calldata: Vec<u8>) -> Result<SubmissionResult, OrchestrationError> {
+        let (config, rpc) = self.solana.as_ref().ok_or_else(|| {
+            OrchestrationError::SubmissionFailed("No Solana RPC configured".into())
+        })?;
+
+        let submission = rpc.send_instruction(config, &calldata).await?;
+        let explorer_url = format!("https://explorer.solana.com/tx/{}", submission.signature);
+        Ok(SubmissionResult::new(
+            submission.signature,
+            true,
+            explorer_url,
+            ChainCost::Solana { compute_units: submission.compute_units },
+        ))
     }
 
     /// Submit to Stellar
-    async fn submit_to_stellar(proof: ProofReceipt) -> Result<SubmissionResult, OrchestrationError> {
+    async fn submit_to_stellar(_calldata: Vec<u8>) -> Result<SubmissionResult, OrchestrationError> {
         // Placeholder - would use Stellar SDK
-        Ok(SubmissionResult {
-            transaction_hash: "stellar_tx_placeholder".to_string(),
-            verification_status: true,
-            explorer_url: "https://stellar.expert/explorer/testnet/tx/placeholder".to_string(),
-            gas_used: Some(100000),
-        })
+        Ok(SubmissionResult::new(
+            "stellar_tx_placeholder",
+            true,
+            "https://stellar.expert/explorer/testnet/tx/placeholder",
+            ChainCost::Stellar { resource_fee: 100000 },
+        ))
     }
 
     /// Submit to EVM (Mantra)
-    async fn submit_to_evm(proof: ProofReceipt) -> Result<SubmissionResult, OrchestrationError> {
+    async fn submit_to_evm(_calldata: Vec<u8>) -> Result<SubmissionResult, OrchestrationError> {
         // Placeholder - would use ethers-rs
-        Ok(SubmissionResult {
-            transaction_hash: "0xplaceholder".to_string(),
-            verification_status: true,
-            explorer_url: "https://explorer.mantra.zone/tx/0xplaceholder".to_string(),
-            gas_used: Some(500000),
-        })
+        Ok(SubmissionResult::new(
+            "0xplaceholder",
+            true,
+            "https://explorer.mantra.zone/tx/0xplaceholder",
+            ChainCost::Evm { gas: 500000, gas_price: 1_000_000_000 },
+        ))
+    }
+
+    /// Submit to CosmWasm (Mantra)
+    async fn submit_to_cosmwasm(_calldata: Vec<u8>) -> Result<SubmissionResult, OrchestrationError> {
+        // Placeholder - would use cosmrs to broadcast a MsgExecuteContract
+        Ok(SubmissionResult::new(
+            "cosmwasm_tx_placeholder",
+            true,
+            "https://www.mintscan.io/mantra/tx/placeholder",
+            ChainCost::Cosmwasm { gas_wanted: 3000000, gas_used: 2600000 },
+        ))
     }
 }
 
@@ -69,10 +320,323 @@ impl ChainOrchestrator {
 pub enum OrchestrationError {
     #[error("Chain not supported: {0:?}")]
     UnsupportedChain(ChainType),
-    
+
+    #[error("Calldata encoding failed: {0}")]
+    Encoding(String),
+
     #[error("Submission failed: {0}")]
     SubmissionFailed(String),
-    
+
     #[error("Verification failed: {0}")]
     VerificationFailed(String),
+
+    #[error("Proof invalid: {0}")]
+    ProofInvalid(String),
+
+    #[error("Event parsing failed: {0}")]
+    EventParse(String),
+
+    #[error("Verifier on {0:?} is not initialized")]
+    VerifierNotInitialized(ChainType),
+
+    #[error("Verifier on {chain:?} uses vkey {actual}, expected {expected}")]
+    VkeyMismatch {
+        chain: ChainType,
+        expected: String,
+        actual: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::events::RawChainEvent;
+    use crate::agent::preflight::VerifierStatus;
+    use crate::agent::solana::SolanaSubmission;
+    use crate::testing::{FaultyEngine, MockEngine};
+    use crate::{PrivacyEngineError, ProofType};
+
+    /// Records the instruction data it is asked to send
+    #[derive(Default)]
+    struct MockSolanaRpc {
+        sent: std::sync::Mutex<Vec<(String, Vec<u8>)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl SolanaRpc for MockSolanaRpc {
+        async fn send_instruction(
+            &self,
+            config: &SolanaConfig,
+            data: &[u8],
+        ) -> Result<SolanaSubmission, OrchestrationError> {
+            self.sent.lock().unwrap().push((config.program_id.clone(), data.to_vec()));
+            Ok(SolanaSubmission { signature: "5igSig".into(), compute_units: 213_000 })
+        }
+    }
+
+    fn with_mock_solana(orchestrator: ChainOrchestrator) -> (ChainOrchestrator, Arc<MockSolanaRpc>) {
+        let rpc = Arc::new(MockSolanaRpc::default());
+        let config = SolanaConfig {
+            rpc_url: "http://127.0.0.1:8899".into(),
+            program_id: "Verifier1111111111111111111111111111111111".into(),
+            accounts: vec![],
+        };
+        (orchestrator.with_solana(config, rpc.clone()), rpc)
+    }
+
+    fn receipt_with_mode(mode: &str) -> ProofReceipt {
+        ProofReceipt {
+            proof_type: ProofType::ZkProof,
+            proof: vec![0xAB; 260],
+            public_values: vec![0u8; 40],
+            metadata: serde_json::to_vec(&serde_json::json!({ "mode": mode })).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stark_receipt_to_stellar_fails_encoding() {
+        let result = ChainOrchestrator::default().submit_proof(receipt_with_mode("stark"), ChainType::Stellar).await;
+        assert!(matches!(result, Err(OrchestrationError::Encoding(_))));
+    }
+
+    struct MockEventSource;
+
+    #[async_trait::async_trait]
+    impl ChainEventSource for MockEventSource {
+        async fn last_event(&self, chain: ChainType) -> Result<RawChainEvent, OrchestrationError> {
+            match chain {
+                ChainType::Stellar => {
+                    let mut data = vec![5u8; 32];
+                    data.extend_from_slice(&1_000u64.to_be_bytes());
+                    data.extend_from_slice(&1u64.to_be_bytes());
+                    data.extend_from_slice(&1_700_000_000u64.to_be_bytes());
+                    Ok(RawChainEvent::Stellar { topics: vec!["proof_verified".to_string()], data })
+                }
+                other => Err(OrchestrationError::UnsupportedChain(other)),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_last_event() {
+        let event = ChainOrchestrator::fetch_last_event(&MockEventSource, ChainType::Stellar)
+            .await
+            .unwrap();
+        assert!(event.matches(&crate::rwa::RwaPublicValues {
+            institutional_pubkey: [5u8; 32],
+            threshold: 1_000,
+            max_threshold: crate::rwa::NO_MAX_THRESHOLD,
+            freshness: Default::default(),
+        }));
+
+        let result = ChainOrchestrator::fetch_last_event(&MockEventSource, ChainType::Solana).await;
+        assert!(matches!(result, Err(OrchestrationError::UnsupportedChain(ChainType::Solana))));
+    }
+
+    #[tokio::test]
+    async fn test_submission_cost_variant_per_chain() {
+        let receipt = receipt_with_mode("groth16");
+
+        let (solana_orchestrator, _) = with_mock_solana(ChainOrchestrator::default());
+        let solana = solana_orchestrator.submit_proof(receipt.clone(), ChainType::Solana).await.unwrap();
+        assert!(matches!(solana.cost, ChainCost::Solana { compute_units } if compute_units > 0));
+
+        let stellar = ChainOrchestrator::default().submit_proof(receipt.clone(), ChainType::Stellar).await.unwrap();
+        assert!(matches!(stellar.cost, ChainCost::Stellar { .. }));
+
+        let cosmwasm = ChainOrchestrator::default().submit_proof(receipt.clone(), ChainType::Cosmwasm).await.unwrap();
+        assert!(matches!(cosmwasm.cost, ChainCost::Cosmwasm { gas_wanted, gas_used } if gas_used <= gas_wanted));
+
+        let evm = ChainOrchestrator::default().submit_proof(receipt, ChainType::Evm).await.unwrap();
+        assert!(matches!(evm.cost, ChainCost::Evm { gas_price, .. } if gas_price > 0));
+
+        #[allow(deprecated)]
+        let legacy = evm.gas_used;
+        assert_eq!(legacy, Some(evm.cost.units()));
+    }
+
+    #[test]
+    fn test_cosmwasm_cost_units() {
+        let cost = ChainCost::Cosmwasm { gas_wanted: 300_000, gas_used: 180_000 };
+        let result = SubmissionResult::new("ABC", true, "https://mintscan.io/tx/ABC", cost);
+        assert_eq!(result.cost.units(), 180_000);
+    }
+
+    #[tokio::test]
+    async fn test_pre_verify_rejects_corrupt_receipt() {
+        let engine = Arc::new(MockEngine::builder().proof(vec![0xAB; 260]).build());
+        let orchestrator = ChainOrchestrator::new_with_engine(
+            OrchestratorConfig { pre_verify: true, ..Default::default() },
+            engine.clone(),
+        );
+
+        let mut corrupt = receipt_with_mode("groth16");
+        corrupt.proof[10] ^= 0xFF;
+        let result = orchestrator.submit(corrupt, ChainType::Evm).await;
+        assert!(matches!(result, Err(OrchestrationError::ProofInvalid(_))));
+
+        let result = orchestrator.submit(receipt_with_mode("groth16"), ChainType::Evm).await;
+        assert!(result.is_ok());
+        assert_eq!(engine.verify_calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_pre_verify_retries_injected_failures() {
+        let flaky = || {
+            Arc::new(
+                FaultyEngine::new(MockEngine::builder().proof(vec![0xAB; 260]).build())
+                    .fail_first(2),
+            )
+        };
+
+        // Not enough retries: the injected error surfaces
+        let engine = flaky();
+        let orchestrator = ChainOrchestrator::new_with_engine(
+            OrchestratorConfig { pre_verify: true, verify_retries: 1 },
+            engine.clone(),
+        );
+        let result = orchestrator.submit(receipt_with_mode("groth16"), ChainType::Evm).await;
+        assert!(matches!(result, Err(OrchestrationError::ProofInvalid(_))));
+        assert_eq!(engine.verify_calls(), 2);
+
+        // Enough retries: the third attempt reaches the real engine and succeeds
+        let engine = flaky();
+        let orchestrator = ChainOrchestrator::new_with_engine(
+            OrchestratorConfig { pre_verify: true, verify_retries: 2 },
+            engine.clone(),
+        );
+        let result = orchestrator.submit(receipt_with_mode("groth16"), ChainType::Evm).await;
+        assert!(result.is_ok());
+        assert_eq!(engine.verify_calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_pre_verify_does_not_retry_permanent_errors() {
+        let engine = Arc::new(
+            FaultyEngine::new(MockEngine::builder().proof(vec![0xAB; 260]).build())
+                .fail_first(1)
+                .with_error(|| PrivacyEngineError::InvalidProofFormat("truncated".into())),
+        );
+        let orchestrator = ChainOrchestrator::new_with_engine(
+            OrchestratorConfig { pre_verify: true, verify_retries: 3 },
+            engine.clone(),
+        );
+        let result = orchestrator.submit(receipt_with_mode("groth16"), ChainType::Evm).await;
+        assert!(matches!(result, Err(OrchestrationError::ProofInvalid(_))));
+        assert_eq!(engine.verify_calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pre_verify_does_not_retry_invalid_proof() {
+        let engine = Arc::new(FaultyEngine::new(MockEngine::with_failing_verify()));
+        let orchestrator = ChainOrchestrator::new_with_engine(
+            OrchestratorConfig { pre_verify: true, verify_retries: 3 },
+            engine.clone(),
+        );
+        let result = orchestrator.submit(receipt_with_mode("groth16"), ChainType::Evm).await;
+        assert!(matches!(result, Err(OrchestrationError::ProofInvalid(_))));
+        assert_eq!(engine.verify_calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pre_verify_without_engine_fails() {
+        let orchestrator = ChainOrchestrator::new(OrchestratorConfig { pre_verify: true, ..Default::default() });
+        let result = orchestrator.submit(receipt_with_mode("groth16"), ChainType::Evm).await;
+        assert!(matches!(result, Err(OrchestrationError::VerificationFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_submission_appends_audit_entry() {
+        let (orchestrator, _) = with_mock_solana(ChainOrchestrator::default());
+        let mut trail = ZkAuditTrail::new();
+
+        let result = orchestrator
+            .submit_with_audit(receipt_with_mode("groth16"), ChainType::Solana, Some(&mut trail))
+            .await
+            .unwrap();
+
+        assert_eq!(trail.len(), 1);
+        let entry = &trail.entries[0];
+        assert!(matches!(entry.action, AgentAction::SubmitToChain));
+        assert_eq!(entry.context.chain, Some(ChainType::Solana));
+        assert!(entry.context.duration_ms.is_some());
+        assert_eq!(entry.output_hash, {
+            use sha2::{Digest, Sha256};
+            <[u8; 32]>::from(Sha256::digest(result.transaction_hash.as_bytes()))
+        });
+        assert!(trail.verify_integrity());
+
+        // Failed submissions are not recorded
+        let failed = orchestrator
+            .submit_with_audit(receipt_with_mode("stark"), ChainType::Solana, Some(&mut trail))
+            .await;
+        assert!(failed.is_err());
+        assert_eq!(trail.len(), 1);
+    }
+
+    const VKEY: [u8; 32] = [0x11; 32];
+
+    /// Solana uninitialized, Stellar initialized with another key, EVM ready
+    struct MockVerifierState;
+
+    #[async_trait::async_trait]
+    impl VerifierStateSource for MockVerifierState {
+        async fn verifier_status(&self, chain: ChainType) -> Result<VerifierStatus, OrchestrationError> {
+            Ok(match chain {
+                ChainType::Solana => VerifierStatus { initialized: false, vkey_hash: None },
+                ChainType::Stellar => VerifierStatus { initialized: true, vkey_hash: Some([0x22; 32]) },
+                ChainType::Evm | ChainType::Cosmwasm => VerifierStatus { initialized: true, vkey_hash: Some(VKEY) },
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_preflight_per_chain() {
+        let orchestrator = ChainOrchestrator::default().with_verifier_state(Arc::new(MockVerifierState));
+
+        assert!(matches!(
+            orchestrator.preflight(ChainType::Solana, VKEY).await,
+            Err(OrchestrationError::VerifierNotInitialized(ChainType::Solana))
+        ));
+        assert!(matches!(
+            orchestrator.preflight(ChainType::Stellar, VKEY).await,
+            Err(OrchestrationError::VkeyMismatch { chain: ChainType::Stellar, .. })
+        ));
+        assert!(orchestrator.preflight(ChainType::Evm, VKEY).await.is_ok());
+
+        let unconfigured = ChainOrchestrator::default();
+        assert!(matches!(
+            unconfigured.preflight(ChainType::Evm, VKEY).await,
+            Err(OrchestrationError::VerificationFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_solana_submission_sends_verify_instruction() {
+        let (orchestrator, rpc) = with_mock_solana(ChainOrchestrator::default());
+        let receipt = receipt_with_mode("groth16");
+
+        let result = orchestrator.submit_proof(receipt.clone(), ChainType::Solana).await.unwrap();
+        assert_eq!(result.transaction_hash, "5igSig");
+        assert_eq!(result.explorer_url, "https://explorer.solana.com/tx/5igSig");
+        assert_eq!(result.cost, ChainCost::Solana { compute_units: 213_000 });
+
+        let (program_id, data) = rpc.sent.lock().unwrap()[0].clone();
+        assert_eq!(program_id, "Verifier1111111111111111111111111111111111");
+        assert_eq!(&data[..8], &crate::calldata::anchor_discriminator("verify_rwa_proof"));
+        let (proof, public_values): (Vec<u8>, Vec<u8>) = borsh::from_slice(&data[8..]).unwrap();
+        assert_eq!(proof, receipt.proof);
+        assert_eq!(public_values, receipt.public_values);
+
+        let unconfigured = ChainOrchestrator::default().submit_proof(receipt, ChainType::Solana).await;
+        assert!(matches!(unconfigured, Err(OrchestrationError::SubmissionFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_groth16_receipt_submits() {
+        let result = ChainOrchestrator::default().submit_proof(receipt_with_mode("groth16"), ChainType::Stellar)
+            .await
+            .unwrap();
+        assert!(result.verification_status);
+    }
 }
@@ -0,0 +1,36 @@
+//! # Verifier Preflight
+//!
+//! On-chain verifier state consulted before submitting a proof, so a
+//! submission that would fail on-chain (uninitialized verifier, wrong program)
+//! is rejected before any fees are paid.
+//!
+//! ## Where the state lives
+//!
+//! - **Solana**: `VkeyAccount.is_initialized` and its stored vkey hash
+//! - **Stellar**: the contract's `init` flag and stored vkey hash
+//! - **EVM**: the deployed verifier contract, whose verifying key is compiled
+//!   in as constants
+//! - **CosmWasm (Mantra)**: presence of the contract's `Config` entry and its
+//!   stored vkey hash
+
+use super::orchestrator::OrchestrationError;
+use crate::ChainType;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Initialization state of a chain's verifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifierStatus {
+    /// Whether the verifier has been initialized with a verifying key
+    pub initialized: bool,
+
+    /// Hash of the stored verifying key, if initialized
+    pub vkey_hash: Option<[u8; 32]>,
+}
+
+/// Source of on-chain verifier state (an RPC client or test mock)
+#[async_trait]
+pub trait VerifierStateSource: Send + Sync {
+    /// Read the current state of the verifier deployed on `chain`
+    async fn verifier_status(&self, chain: ChainType) -> Result<VerifierStatus, OrchestrationError>;
+}
@@ -0,0 +1,363 @@
+//! # Solana Submission
+//!
+//! Sends the `verify_rwa_proof` instruction to the deployed Solana verifier
+//! program. The instruction data is the Anchor calldata produced by
+//! `ProofReceipt::to_chain_calldata`.
+//!
+//! The RPC side sits behind `SolanaRpc` so the orchestrator can be tested
+//! without a validator. `RpcSolanaClient` is the JSON-RPC implementation: it
+//! builds a legacy transaction with the fee payer as the only signer, sends
+//! it, and waits for confirmation to read the compute units consumed.
+
+use super::orchestrator::OrchestrationError;
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ed25519_dalek::{Signer, SigningKey};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Times `getTransaction` is polled before giving up on confirmation
+const CONFIRM_ATTEMPTS: u32 = 30;
+
+/// Default delay between confirmation polls
+pub const DEFAULT_CONFIRM_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Extra (non-signer) account passed to the verifier instruction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolanaAccountMeta {
+    /// Base58 account address
+    pub pubkey: String,
+
+    /// Whether the instruction writes to the account
+    pub is_writable: bool,
+}
+
+/// Where to send Solana verifier instructions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolanaConfig {
+    /// JSON-RPC endpoint (e.g. `https://api.devnet.solana.com`)
+    pub rpc_url: String,
+
+    /// Base58 address of the deployed verifier program
+    pub program_id: String,
+
+    /// Accounts required by `verify_rwa_proof`, in instruction order
+    #[serde(default)]
+    pub accounts: Vec<SolanaAccountMeta>,
+}
+
+/// Outcome of a confirmed verifier transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaSubmission {
+    /// Base58 transaction signature
+    pub signature: String,
+
+    /// Compute units consumed by the transaction
+    pub compute_units: u64,
+}
+
+/// Sends verifier instructions to a Solana cluster (an RPC client or test mock)
+#[async_trait]
+pub trait SolanaRpc: Send + Sync {
+    /// Send one instruction with `data` to `config.program_id` and wait for confirmation
+    async fn send_instruction(
+        &self,
+        config: &SolanaConfig,
+        data: &[u8],
+    ) -> Result<SolanaSubmission, OrchestrationError>;
+}
+
+/// `SolanaRpc` over the Solana JSON-RPC API, paying fees from `payer`
+pub struct RpcSolanaClient {
+    client: reqwest::Client,
+    payer: SigningKey,
+    confirm_interval: Duration,
+}
+
+impl RpcSolanaClient {
+    /// Create a client that signs and pays for transactions with `payer`
+    pub fn new(payer: SigningKey) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            payer,
+            confirm_interval: DEFAULT_CONFIRM_INTERVAL,
+        }
+    }
+
+    /// Override the delay between confirmation polls
+    pub fn with_confirm_interval(mut self, interval: Duration) -> Self {
+        self.confirm_interval = interval;
+        self
+    }
+
+    /// Base58 address of the fee payer
+    pub fn payer_address(&self) -> String {
+        bs58::encode(self.payer.verifying_key().as_bytes()).into_string()
+    }
+
+    /// Make a JSON-RPC call and return its `result`
+    async fn call(&self, url: &str, method: &str, params: Value) -> Result<Value, OrchestrationError> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response: Value = self.client
+            .post(url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| OrchestrationError::SubmissionFailed(format!("{} request failed: {}", method, e)))?
+            .json()
+            .await
+            .map_err(|e| OrchestrationError::SubmissionFailed(format!("{} returned invalid JSON: {}", method, e)))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(OrchestrationError::SubmissionFailed(format!("{} failed: {}", method, error)));
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| OrchestrationError::SubmissionFailed(format!("{} returned no result", method)))
+    }
+}
+
+#[async_trait]
+impl SolanaRpc for RpcSolanaClient {
+    async fn send_instruction(
+        &self,
+        config: &SolanaConfig,
+        data: &[u8],
+    ) -> Result<SolanaSubmission, OrchestrationError> {
+        let program_id = decode_address(&config.program_id)?;
+        let accounts = config
+            .accounts
+            .iter()
+            .map(|meta| Ok((decode_address(&meta.pubkey)?, meta.is_writable)))
+            .collect::<Result<Vec<_>, OrchestrationError>>()?;
+
+        let latest = self
+            .call(&config.rpc_url, "getLatestBlockhash", json!([{ "commitment": "confirmed" }]))
+            .await?;
+        let blockhash = latest["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| OrchestrationError::SubmissionFailed("getLatestBlockhash returned no blockhash".into()))
+            .and_then(decode_address)?;
+
+        let payer = self.payer.verifying_key().to_bytes();
+        let message = build_message(&payer, &program_id, &accounts, &blockhash, data)?;
+        let mut transaction = Vec::with_capacity(1 + 64 + message.len());
+        push_compact_len(&mut transaction, 1);
+        transaction.extend_from_slice(&self.payer.sign(&message).to_bytes());
+        transaction.extend_from_slice(&message);
+
+        let signature = self
+            .call(
+                &config.rpc_url,
+                "sendTransaction",
+                json!([STANDARD.encode(&transaction), { "encoding": "base64" }]),
+            )
+            .await?
+            .as_str()
+            .ok_or_else(|| OrchestrationError::SubmissionFailed("sendTransaction returned no signature".into()))?
+            .to_string();
+
+        for _ in 0..CONFIRM_ATTEMPTS {
+            let confirmed = self
+                .call(
+                    &config.rpc_url,
+                    "getTransaction",
+                    json!([signature, {
+                        "encoding": "json",
+                        "commitment": "confirmed",
+                        "maxSupportedTransactionVersion": 0
+                    }]),
+                )
+                .await?;
+            if confirmed.is_null() {
+                tokio::time::sleep(self.confirm_interval).await;
+                continue;
+            }
+
+            let meta = &confirmed["meta"];
+            if !meta["err"].is_null() {
+                return Err(OrchestrationError::SubmissionFailed(format!(
+                    "Transaction {} failed: {}",
+                    signature, meta["err"]
+                )));
+            }
+            return Ok(SolanaSubmission {
+                compute_units: meta["computeUnitsConsumed"].as_u64().unwrap_or(0),
+                signature,
+            });
+        }
+
+        Err(OrchestrationError::SubmissionFailed(format!(
+            "Transaction {} was not confirmed",
+            signature
+        )))
+    }
+}
+
+/// Decode a base58 address (or blockhash) into its 32 bytes
+fn decode_address(address: &str) -> Result<[u8; 32], OrchestrationError> {
+    let bytes = bs58::decode(address)
+        .into_vec()
+        .map_err(|e| OrchestrationError::SubmissionFailed(format!("Invalid base58 '{}': {}", address, e)))?;
+    bytes
+        .try_into()
+        .map_err(|_| OrchestrationError::SubmissionFailed(format!("'{}' is not 32 bytes", address)))
+}
+
+/// Append a Solana `compact-u16` length
+fn push_compact_len(out: &mut Vec<u8>, len: u16) {
+    let mut rem = len;
+    loop {
+        let byte = (rem & 0x7f) as u8;
+        rem >>= 7;
+        if rem == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Serialize a legacy message holding one instruction to `program_id`
+///
+/// `payer` is the only signer. Account keys are ordered as Solana requires:
+/// the payer, writable `accounts`, read-only `accounts`, then the program.
+///
+/// # Errors
+///
+/// Returns `OrchestrationError::Encoding` if `data` or the account list is
+/// too long to encode
+pub fn build_message(
+    payer: &[u8; 32],
+    program_id: &[u8; 32],
+    accounts: &[([u8; 32], bool)],
+    blockhash: &[u8; 32],
+    data: &[u8],
+) -> Result<Vec<u8>, OrchestrationError> {
+    let mut keys = vec![*payer];
+    keys.extend(accounts.iter().filter(|(_, writable)| *writable).map(|(key, _)| *key));
+    keys.extend(accounts.iter().filter(|(_, writable)| !*writable).map(|(key, _)| *key));
+    keys.push(*program_id);
+
+    let too_long = |what: &str| OrchestrationError::Encoding(format!("Solana instruction has too many {}", what));
+    let key_count = u8::try_from(keys.len()).map_err(|_| too_long("accounts"))?;
+    let readonly_unsigned = accounts.iter().filter(|(_, writable)| !*writable).count() as u8 + 1;
+    let data_len = u16::try_from(data.len()).map_err(|_| too_long("data bytes"))?;
+
+    let mut message = vec![1, 0, readonly_unsigned];
+    push_compact_len(&mut message, key_count as u16);
+    for key in &keys {
+        message.extend_from_slice(key);
+    }
+    message.extend_from_slice(blockhash);
+
+    // One instruction: program index, account indices, data
+    push_compact_len(&mut message, 1);
+    message.push(key_count - 1);
+    push_compact_len(&mut message, accounts.len() as u16);
+    for (key, _) in accounts {
+        let index = keys.iter().position(|k| k == key).expect("every account is in keys");
+        message.push(index as u8);
+    }
+    push_compact_len(&mut message, data_len);
+    message.extend_from_slice(data);
+
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signature, Verifier};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_compact_len_encoding() {
+        for (len, expected) in [(0u16, vec![0x00]), (0x7f, vec![0x7f]), (300, vec![0xac, 0x02]), (0x4000, vec![0x80, 0x80, 0x01])] {
+            let mut out = Vec::new();
+            push_compact_len(&mut out, len);
+            assert_eq!(out, expected, "{}", len);
+        }
+    }
+
+    #[test]
+    fn test_message_layout() {
+        let (payer, program, writable, readonly, blockhash) = ([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32], [9u8; 32]);
+        let message = build_message(&payer, &program, &[(readonly, false), (writable, true)], &blockhash, &[7, 7]).unwrap();
+
+        // Header: 1 signer, 0 read-only signers, 2 read-only non-signers
+        assert_eq!(&message[..4], &[1, 0, 2, 4]);
+        assert_eq!(&message[4..36], &payer);
+        assert_eq!(&message[36..68], &writable);
+        assert_eq!(&message[68..100], &readonly);
+        assert_eq!(&message[100..132], &program);
+        assert_eq!(&message[132..164], &blockhash);
+        // 1 instruction, program index 3, accounts [readonly=2, writable=1], data
+        assert_eq!(&message[164..], &[1, 3, 2, 2, 1, 2, 7, 7]);
+    }
+
+    /// Minimal JSON-RPC validator: checks the payer's signature on every
+    /// transaction and reports it confirmed on the second poll
+    async fn serve_rpc(payer: ed25519_dalek::VerifyingKey) -> (String, Arc<Mutex<Vec<u8>>>) {
+        use axum::{routing::post, Json, Router};
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let polls = Arc::new(AtomicUsize::new(0));
+        let blockhash = bs58::encode([5u8; 32]).into_string();
+        let recorded = sent.clone();
+
+        let handler = move |Json(request): Json<Value>| {
+            let (sent, polls, blockhash) = (recorded.clone(), polls.clone(), blockhash.clone());
+            async move {
+                let result = match request["method"].as_str().unwrap() {
+                    "getLatestBlockhash" => json!({ "value": { "blockhash": blockhash, "lastValidBlockHeight": 1 } }),
+                    "sendTransaction" => {
+                        let tx = STANDARD.decode(request["params"][0].as_str().unwrap()).unwrap();
+                        let signature = Signature::from_slice(&tx[1..65]).unwrap();
+                        payer.verify(&tx[65..], &signature).unwrap();
+                        *sent.lock().unwrap() = tx.clone();
+                        json!(bs58::encode(&tx[1..65]).into_string())
+                    }
+                    "getTransaction" if polls.fetch_add(1, Ordering::SeqCst) == 0 => Value::Null,
+                    "getTransaction" => json!({ "meta": { "err": null, "computeUnitsConsumed": 212345 } }),
+                    other => panic!("unexpected method {}", other),
+                };
+                Json(json!({ "jsonrpc": "2.0", "id": 1, "result": result }))
+            }
+        };
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, Router::new().route("/", post(handler))).await.unwrap() });
+        (format!("http://{}/", addr), sent)
+    }
+
+    #[tokio::test]
+    async fn test_rpc_client_sends_signed_transaction() {
+        let payer = SigningKey::from_bytes(&[42u8; 32]);
+        let (rpc_url, sent) = serve_rpc(payer.verifying_key()).await;
+        let client = RpcSolanaClient::new(payer).with_confirm_interval(Duration::from_millis(1));
+        let config = SolanaConfig {
+            rpc_url,
+            program_id: bs58::encode([2u8; 32]).into_string(),
+            accounts: vec![SolanaAccountMeta { pubkey: bs58::encode([3u8; 32]).into_string(), is_writable: false }],
+        };
+
+        let submission = client.send_instruction(&config, &[0xDE, 0xAD]).await.unwrap();
+        assert_eq!(submission.compute_units, 212345);
+
+        let tx = sent.lock().unwrap().clone();
+        assert_eq!(submission.signature, bs58::encode(&tx[1..65]).into_string());
+        assert!(tx.ends_with(&[1, 2, 1, 1, 2, 0xDE, 0xAD]));
+        assert_eq!(client.payer_address(), bs58::encode(&tx[69..101]).into_string());
+
+        let bad = SolanaConfig { program_id: "not-base58!".into(), ..config };
+        assert!(matches!(
+            client.send_instruction(&bad, &[]).await,
+            Err(OrchestrationError::SubmissionFailed(_))
+        ));
+    }
+}
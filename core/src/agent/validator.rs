@@ -32,6 +32,16 @@ impl SchemaValidator {
             errors.push("Threshold cannot exceed balance".to_string());
         }
 
+        // Validate range bounds are ordered
+        if claim.threshold > claim.max_threshold {
+            errors.push("Minimum threshold cannot exceed maximum threshold".to_string());
+        }
+
+        // Validate balance is within the upper bound
+        if claim.balance > claim.max_threshold {
+            errors.push("Balance cannot exceed maximum threshold".to_string());
+        }
+
         // Validate pubkey is not all zeros
         if claim.institutional_pubkey == [0u8; 32] {
             warnings.push("Institutional pubkey appears to be placeholder".to_string());
@@ -61,22 +71,43 @@ mod tests {
 
     #[test]
     fn test_valid_claim() {
-        let claim = RwaClaim::new([1u8; 32], 1000000, 500000, [2u8; 64]);
+        let claim = RwaClaim::new([1u8; 32], 1000000, 500000, 1_735_128_100, [9u8; 16], [2u8; 64]);
         let result = SchemaValidator::validate(&claim);
         assert!(result.is_valid);
     }
 
     #[test]
     fn test_zero_balance() {
-        let claim = RwaClaim::new([1u8; 32], 0, 500000, [2u8; 64]);
+        let claim = RwaClaim::new([1u8; 32], 0, 500000, 1_735_128_100, [9u8; 16], [2u8; 64]);
         let result = SchemaValidator::validate(&claim);
         assert!(!result.is_valid);
         assert!(result.errors.iter().any(|e| e.contains("Balance cannot be zero")));
     }
 
+    #[test]
+    fn test_inverted_range_rejected() {
+        let claim = RwaClaim::new([1u8; 32], 1000000, 500000, 1_735_128_100, [9u8; 16], [2u8; 64])
+            .with_max_threshold(400000);
+        let result = SchemaValidator::validate(&claim);
+        assert!(!result.is_valid);
+        assert!(result.errors.iter().any(|e| e.contains("cannot exceed maximum")));
+    }
+
+    #[test]
+    fn test_balance_above_max_threshold_rejected() {
+        let claim = RwaClaim::new([1u8; 32], 1000000, 500000, 1_735_128_100, [9u8; 16], [2u8; 64])
+            .with_max_threshold(900000);
+        let result = SchemaValidator::validate(&claim);
+        assert!(!result.is_valid);
+        assert!(result.errors.iter().any(|e| e.contains("Balance cannot exceed maximum")));
+
+        let in_range = claim.with_max_threshold(1000000);
+        assert!(SchemaValidator::validate(&in_range).is_valid);
+    }
+
     #[test]
     fn test_threshold_exceeds_balance() {
-        let claim = RwaClaim::new([1u8; 32], 100000, 500000, [2u8; 64]);
+        let claim = RwaClaim::new([1u8; 32], 100000, 500000, 1_735_128_100, [9u8; 16], [2u8; 64]);
         let result = SchemaValidator::validate(&claim);
         assert!(!result.is_valid);
     }
@@ -0,0 +1,248 @@
+//! # Chain Calldata Encoding
+//!
+//! Converts a `ProofReceipt` into the argument bytes expected by each chain's
+//! on-chain verifier. This is the single place calldata is derived, shared by
+//! the `ChainOrchestrator` and any tooling that needs to inspect it.
+//!
+//! ## Layouts
+//!
+//! - **Solana**: Anchor instruction data for `verify_rwa_proof(proof, public_values)`:
+//!   8-byte discriminator `sha256("global:verify_rwa_proof")[..8]` followed by
+//!   Borsh-encoded `Vec<u8>` arguments
+//! - **Stellar**: Arguments for `verify_proof(proof, public_values)`, each as a
+//!   big-endian `u32` length prefix followed by the bytes
+//! - **EVM**: ABI-encoded call to `verifyProof(bytes publicValues, bytes proofBytes)`
+//! - **CosmWasm**: JSON execute message
+//!   `{"verify_proof":{"proof":"<base64>","public_values":"<base64>"}}`,
+//!   with both arguments in CosmWasm's base64 `Binary` encoding
+//!
+//! All supported chains verify Groth16 proofs, so only receipts whose metadata
+//! marks them as Groth16 are encoded. STARK and mock proofs, and receipts with
+//! no recognisable `mode` (missing, or non-JSON metadata), are rejected before
+//! any encoding happens.
+
+use crate::{ChainType, PrivacyEngineError, ProofReceipt, ProofType};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ethers_core::abi::{self, Token};
+use sha2::{Digest, Sha256};
+
+/// Anchor instruction name of the Solana verifier entry point
+const SOLANA_VERIFY_IX: &str = "verify_rwa_proof";
+
+/// Solidity signature of the EVM verifier entry point
+const EVM_VERIFY_SIGNATURE: &str = "verifyProof(bytes,bytes)";
+
+/// Execute message variant of the CosmWasm verifier entry point
+const COSMWASM_VERIFY_MSG: &str = "verify_proof";
+
+/// Proving modes whose proofs the on-chain verifiers accept
+const ONCHAIN_MODES: &[&str] = &["groth16"];
+
+impl ProofReceipt {
+    /// Encode this receipt as calldata for the target chain's verifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - The chain whose verifier will receive the calldata
+    ///
+    /// # Errors
+    ///
+    /// Returns `PrivacyEngineError::InvalidInput` if the receipt cannot be
+    /// verified on-chain (TEE attestations, STARK/mock proofs for
+    /// Groth16-only verifiers, or metadata that doesn't name a mode)
+    pub fn to_chain_calldata(&self, chain: ChainType) -> Result<Vec<u8>, PrivacyEngineError> {
+        if self.proof_type == ProofType::TeeAttestation {
+            return Err(PrivacyEngineError::InvalidInput(format!(
+                "TEE attestations cannot be verified on {:?}",
+                chain
+            )));
+        }
+
+        match self.proving_mode() {
+            Some(mode) if ONCHAIN_MODES.contains(&mode.as_str()) => {}
+            Some(mode) => {
+                return Err(PrivacyEngineError::InvalidInput(format!(
+                    "{} proofs cannot be verified on {:?}; regenerate the proof in groth16 mode",
+                    mode, chain
+                )));
+            }
+            None => {
+                return Err(PrivacyEngineError::InvalidInput(format!(
+                    "receipt metadata has no proving mode; cannot tell whether {:?} can verify it",
+                    chain
+                )));
+            }
+        }
+
+        let calldata = match chain {
+            ChainType::Solana => {
+                let mut data = anchor_discriminator(SOLANA_VERIFY_IX).to_vec();
+                let args = (self.proof.clone(), self.public_values.clone());
+                borsh::to_writer(&mut data, &args)
+                    .map_err(|e| PrivacyEngineError::SerializationError(e.to_string()))?;
+                data
+            }
+            ChainType::Stellar => {
+                let mut data = Vec::with_capacity(8 + self.proof.len() + self.public_values.len());
+                for arg in [&self.proof, &self.public_values] {
+                    data.extend_from_slice(&(arg.len() as u32).to_be_bytes());
+                    data.extend_from_slice(arg);
+                }
+                data
+            }
+            ChainType::Evm => {
+                let mut data = ethers_core::utils::id(EVM_VERIFY_SIGNATURE).to_vec();
+                data.extend(abi::encode(&[
+                    Token::Bytes(self.public_values.clone()),
+                    Token::Bytes(self.proof.clone()),
+                ]));
+                data
+            }
+            ChainType::Cosmwasm => {
+                let msg = serde_json::json!({
+                    COSMWASM_VERIFY_MSG: {
+                        "proof": STANDARD.encode(&self.proof),
+                        "public_values": STANDARD.encode(&self.public_values),
+                    }
+                });
+                serde_json::to_vec(&msg)
+                    .map_err(|e| PrivacyEngineError::SerializationError(e.to_string()))?
+            }
+        };
+
+        Ok(calldata)
+    }
+
+    /// Proving mode from the metadata, falling back to the one `proof_type` implies.
+    ///
+    /// A mode recorded in metadata wins, so a `Groth16` receipt whose metadata
+    /// says `stark` is treated as STARK and rejected.
+    pub(crate) fn proving_mode(&self) -> Option<String> {
+        self.metadata_mode()
+            .or_else(|| self.proof_type.mode().map(str::to_string))
+    }
+
+    /// Proving mode recorded in JSON metadata (e.g. `{"mode": "groth16"}`), if any.
+    pub(crate) fn metadata_mode(&self) -> Option<String> {
+        let metadata: serde_json::Value = serde_json::from_slice(&self.metadata).ok()?;
+        metadata
+            .get("mode")?
+            .as_str()
+            .map(|mode| mode.to_ascii_lowercase())
+    }
+}
+
+/// Anchor instruction discriminator: first 8 bytes of `sha256("global:<name>")`.
+pub fn anchor_discriminator(ix_name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("global:{}", ix_name).as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash[..8]);
+    discriminator
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt_with_mode(mode: &str) -> ProofReceipt {
+        ProofReceipt {
+            proof_type: ProofType::ZkProof,
+            proof: vec![1, 2, 3],
+            public_values: vec![4, 5],
+            metadata: serde_json::to_vec(&serde_json::json!({ "mode": mode })).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_solana_calldata_layout() {
+        let receipt = receipt_with_mode("groth16");
+        let data = receipt.to_chain_calldata(ChainType::Solana).unwrap();
+
+        assert_eq!(&data[..8], &anchor_discriminator("verify_rwa_proof"));
+        // Borsh Vec<u8>: u32 LE length prefix then bytes
+        assert_eq!(&data[8..12], &3u32.to_le_bytes());
+        assert_eq!(&data[12..15], &[1, 2, 3]);
+        assert_eq!(&data[15..19], &2u32.to_le_bytes());
+        assert_eq!(&data[19..], &[4, 5]);
+    }
+
+    #[test]
+    fn test_stellar_calldata_layout() {
+        let receipt = receipt_with_mode("groth16");
+        let data = receipt.to_chain_calldata(ChainType::Stellar).unwrap();
+
+        assert_eq!(data, vec![0, 0, 0, 3, 1, 2, 3, 0, 0, 0, 2, 4, 5]);
+    }
+
+    #[test]
+    fn test_evm_calldata_selector() {
+        let receipt = receipt_with_mode("groth16");
+        let data = receipt.to_chain_calldata(ChainType::Evm).unwrap();
+
+        assert_eq!(&data[..4], &ethers_core::utils::id("verifyProof(bytes,bytes)"));
+        let decoded = abi::decode(
+            &[abi::ParamType::Bytes, abi::ParamType::Bytes],
+            &data[4..],
+        )
+        .unwrap();
+        assert_eq!(decoded[0], Token::Bytes(vec![4, 5]));
+        assert_eq!(decoded[1], Token::Bytes(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_cosmwasm_execute_msg() {
+        let receipt = receipt_with_mode("groth16");
+        let data = receipt.to_chain_calldata(ChainType::Cosmwasm).unwrap();
+
+        let msg: serde_json::Value = serde_json::from_slice(&data).unwrap();
+        assert_eq!(msg["verify_proof"]["proof"], STANDARD.encode([1, 2, 3]));
+        assert_eq!(msg["verify_proof"]["public_values"], STANDARD.encode([4, 5]));
+    }
+
+    #[test]
+    fn test_stark_receipt_rejected() {
+        let receipt = receipt_with_mode("stark");
+        for chain in [ChainType::Solana, ChainType::Stellar, ChainType::Evm, ChainType::Cosmwasm] {
+            assert!(matches!(
+                receipt.to_chain_calldata(chain),
+                Err(PrivacyEngineError::InvalidInput(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_receipt_without_mode_rejected() {
+        let mut receipt = receipt_with_mode("groth16");
+        for metadata in [Vec::new(), b"not json".to_vec(), br#"{"cycles":42}"#.to_vec(), br#"{"mode":7}"#.to_vec()] {
+            receipt.metadata = metadata;
+            for chain in [ChainType::Solana, ChainType::Stellar, ChainType::Evm, ChainType::Cosmwasm] {
+                assert!(matches!(
+                    receipt.to_chain_calldata(chain),
+                    Err(PrivacyEngineError::InvalidInput(msg)) if msg.contains("no proving mode")
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_groth16_proof_type_without_mode_accepted() {
+        let mut receipt = receipt_with_mode("groth16");
+        receipt.metadata = Vec::new();
+        receipt.proof_type = ProofType::Groth16;
+        assert_eq!(
+            receipt.to_chain_calldata(ChainType::Stellar).unwrap(),
+            receipt_with_mode("groth16").to_chain_calldata(ChainType::Stellar).unwrap()
+        );
+
+        // Metadata that says otherwise wins
+        receipt.metadata = serde_json::to_vec(&serde_json::json!({ "mode": "stark" })).unwrap();
+        assert!(receipt.to_chain_calldata(ChainType::Stellar).is_err());
+    }
+
+    #[test]
+    fn test_tee_attestation_rejected() {
+        let mut receipt = receipt_with_mode("groth16");
+        receipt.proof_type = ProofType::TeeAttestation;
+        assert!(receipt.to_chain_calldata(ChainType::Evm).is_err());
+    }
+}
@@ -0,0 +1,300 @@
+//! # On-Chain Cost Estimation
+//!
+//! Offline, heuristic estimates of what it costs to verify a `ProofReceipt` on
+//! each chain. These are not simulations: they combine a fixed verification
+//! cost for the proof system with a per-byte cost for the calldata, so the CLI
+//! and agents can get a fast figure before making a live RPC estimate.
+//!
+//! ## Heuristics
+//!
+//! - **Solana** (compute units): Groth16 verification via the `alt_bn128`
+//!   syscalls costs roughly 200k CU for the pairing check plus scalar
+//!   multiplications for the public inputs; instruction data costs are small
+//!   and modelled at 8 CU/byte for deserialization.
+//! - **Stellar** (CPU instructions): BN254 verification in a Soroban contract
+//!   is dominated by the pairing at roughly 40M instructions; argument
+//!   decoding is modelled at 500 instructions/byte.
+//! - **EVM** (gas): 21,000 base transaction gas, the `ecPairing` precompile at
+//!   45,000 + 34,000 per pair (4 pairs for Groth16), ~25,000 gas of `ecMul` /
+//!   `ecAdd` and contract overhead, and the exact EIP-2028 calldata cost
+//!   (16 gas per non-zero byte, 4 per zero byte).
+//! - **CosmWasm** (Cosmos SDK gas): without a BN254 host API the pairing runs
+//!   in Wasm, modelled at 2.5M gas, plus ~80,000 gas of transaction overhead
+//!   (signature check, fee deduction) and the SDK's 10 gas per transaction byte.
+//!
+//! STARK-family receipts (`stark`, `compressed`, `core`) have no on-chain
+//! verifier; their estimate multiplies the Groth16 base by
+//! [`STARK_VERIFY_MULTIPLIER`] and charges for the much larger proof, and is
+//! flagged as not verifiable. TEE attestations and `mock` receipts are also
+//! flagged, with only the data cost counted.
+
+use crate::groth16::{PROOF_BYTES, SP1_SELECTOR_BYTES};
+use crate::{ChainType, ProofReceipt, ProofType};
+use serde::{Deserialize, Serialize};
+
+/// Solana: base compute units for a Groth16 verification
+pub const SOLANA_GROTH16_BASE_CU: u64 = 280_000;
+
+/// Solana: compute units per byte of instruction data
+pub const SOLANA_CU_PER_BYTE: u64 = 8;
+
+/// Stellar: base CPU instructions for a Groth16 verification
+pub const STELLAR_GROTH16_BASE_INSTRUCTIONS: u64 = 40_000_000;
+
+/// Stellar: CPU instructions per byte of contract arguments
+pub const STELLAR_INSTRUCTIONS_PER_BYTE: u64 = 500;
+
+/// EVM: intrinsic transaction gas
+pub const EVM_TX_BASE_GAS: u64 = 21_000;
+
+/// EVM: `ecPairing` precompile cost for the 4 Groth16 pairs plus `ecMul`/`ecAdd`
+/// and contract overhead
+pub const EVM_GROTH16_VERIFY_GAS: u64 = 45_000 + 34_000 * 4 + 25_000;
+
+/// EVM: calldata gas per non-zero byte (EIP-2028)
+pub const EVM_NONZERO_BYTE_GAS: u64 = 16;
+
+/// EVM: calldata gas per zero byte
+pub const EVM_ZERO_BYTE_GAS: u64 = 4;
+
+/// CosmWasm: transaction overhead (signature verification, fee handling)
+pub const COSMWASM_TX_BASE_GAS: u64 = 80_000;
+
+/// CosmWasm: gas for a Groth16 verification executed in Wasm
+pub const COSMWASM_GROTH16_VERIFY_GAS: u64 = 2_500_000;
+
+/// CosmWasm: gas per transaction byte (`TxSizeCostPerByte`)
+pub const COSMWASM_GAS_PER_BYTE: u64 = 10;
+
+/// Rough cost ratio of a hypothetical on-chain STARK verifier vs. Groth16
+pub const STARK_VERIFY_MULTIPLIER: u64 = 20;
+
+/// Unit an estimate is expressed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CostUnit {
+    /// Solana compute units
+    ComputeUnits,
+
+    /// Soroban CPU instructions
+    CpuInstructions,
+
+    /// EVM gas
+    Gas,
+
+    /// Cosmos SDK gas
+    CosmosGas,
+}
+
+/// Heuristic on-chain verification cost of a receipt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CostEstimate {
+    /// Target chain
+    pub chain: ChainType,
+
+    /// Unit of `amount`
+    pub unit: CostUnit,
+
+    /// Estimated cost
+    pub amount: u64,
+
+    /// Whether the chain's verifier can actually accept this receipt
+    pub onchain_verifiable: bool,
+}
+
+/// Public inputs of an SP1 Groth16 proof (program vkey hash, public values digest)
+pub const SP1_GROTH16_PUBLIC_INPUTS: usize = 2;
+
+/// Expected per-proof cost of an exported verifier, for budgeting before deployment
+///
+/// Returned alongside the bytecode by `PrivacyEngine::export_verifier_with_report`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifierReport {
+    /// Target chain
+    pub chain: ChainType,
+
+    /// Unit of `estimated_compute_units` (compute units, gas, ...)
+    pub unit: CostUnit,
+
+    /// Estimated cost of one verification, excluding public values calldata
+    pub estimated_compute_units: u64,
+
+    /// Size of the proof the verifier accepts
+    pub proof_bytes: usize,
+
+    /// Number of public inputs the verifier checks
+    pub public_input_count: usize,
+}
+
+impl VerifierReport {
+    /// Report for a verifier of SP1 Groth16 proofs on `chain`
+    ///
+    /// Uses the same heuristics as `estimate_onchain_cost`, with every proof
+    /// byte non-zero so the EVM calldata figure is an upper bound.
+    pub fn sp1_groth16(chain: ChainType) -> Self {
+        let proof_bytes = SP1_SELECTOR_BYTES + PROOF_BYTES;
+        let receipt = ProofReceipt {
+            proof_type: ProofType::ZkProof,
+            proof: vec![0xFF; proof_bytes],
+            public_values: Vec::new(),
+            metadata: br#"{"mode":"groth16"}"#.to_vec(),
+        };
+        let estimate = receipt.estimate_onchain_cost(chain);
+
+        Self {
+            chain,
+            unit: estimate.unit,
+            estimated_compute_units: estimate.amount,
+            proof_bytes,
+            public_input_count: SP1_GROTH16_PUBLIC_INPUTS,
+        }
+    }
+}
+
+/// Proof system as far as cost estimation is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CostClass {
+    Groth16,
+    Stark,
+    Unverifiable,
+}
+
+impl ProofReceipt {
+    /// Estimate the cost of verifying this receipt on `chain`.
+    ///
+    /// The estimate is offline and heuristic; see the module docs for the
+    /// constants used. Receipts with no `mode` in their metadata, and no
+    /// `ProofType::Groth16` to imply one, are treated as unverifiable,
+    /// matching `to_chain_calldata`.
+    pub fn estimate_onchain_cost(&self, chain: ChainType) -> CostEstimate {
+        let class = match (self.proof_type, self.proving_mode().as_deref()) {
+            (ProofType::TeeAttestation, _) => CostClass::Unverifiable,
+            (_, Some("groth16")) => CostClass::Groth16,
+            (_, Some("stark" | "compressed" | "core")) => CostClass::Stark,
+            _ => CostClass::Unverifiable,
+        };
+
+        // Encoded calldata when the receipt is verifiable, otherwise the raw
+        // payload is the best approximation of what would be sent
+        let data = self.to_chain_calldata(chain).unwrap_or_else(|_| {
+            let mut raw = self.proof.clone();
+            raw.extend_from_slice(&self.public_values);
+            raw
+        });
+        let len = data.len() as u64;
+
+        let (unit, base, data_cost) = match chain {
+            ChainType::Solana => (
+                CostUnit::ComputeUnits,
+                SOLANA_GROTH16_BASE_CU,
+                len * SOLANA_CU_PER_BYTE,
+            ),
+            ChainType::Stellar => (
+                CostUnit::CpuInstructions,
+                STELLAR_GROTH16_BASE_INSTRUCTIONS,
+                len * STELLAR_INSTRUCTIONS_PER_BYTE,
+            ),
+            ChainType::Evm => {
+                let calldata_gas: u64 = data
+                    .iter()
+                    .map(|&b| if b == 0 { EVM_ZERO_BYTE_GAS } else { EVM_NONZERO_BYTE_GAS })
+                    .sum();
+                (CostUnit::Gas, EVM_GROTH16_VERIFY_GAS, EVM_TX_BASE_GAS + calldata_gas)
+            }
+            ChainType::Cosmwasm => (
+                CostUnit::CosmosGas,
+                COSMWASM_GROTH16_VERIFY_GAS,
+                COSMWASM_TX_BASE_GAS + len * COSMWASM_GAS_PER_BYTE,
+            ),
+        };
+
+        let verify_cost = match class {
+            CostClass::Groth16 => base,
+            CostClass::Stark => base * STARK_VERIFY_MULTIPLIER,
+            CostClass::Unverifiable => 0,
+        };
+
+        CostEstimate {
+            chain,
+            unit,
+            amount: verify_cost + data_cost,
+            onchain_verifiable: class == CostClass::Groth16,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt(mode: &str, proof_len: usize) -> ProofReceipt {
+        ProofReceipt {
+            proof_type: ProofType::ZkProof,
+            proof: vec![0xAB; proof_len],
+            public_values: vec![1u8; 40],
+            metadata: serde_json::to_vec(&serde_json::json!({ "mode": mode })).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_groth16_vs_stark_estimates() {
+        let groth16 = receipt("groth16", 260);
+        let stark = receipt("stark", 1_500_000);
+
+        for chain in [ChainType::Solana, ChainType::Stellar, ChainType::Evm, ChainType::Cosmwasm] {
+            let g = groth16.estimate_onchain_cost(chain);
+            let s = stark.estimate_onchain_cost(chain);
+
+            assert!(g.onchain_verifiable);
+            assert!(!s.onchain_verifiable);
+            assert_eq!(g.unit, s.unit);
+            assert!(s.amount > g.amount * 10, "{:?}: {} vs {}", chain, s.amount, g.amount);
+        }
+    }
+
+    #[test]
+    fn test_evm_groth16_estimate_breakdown() {
+        let groth16 = receipt("groth16", 260);
+        let calldata = groth16.to_chain_calldata(ChainType::Evm).unwrap();
+        let zeros = calldata.iter().filter(|&&b| b == 0).count() as u64;
+        let nonzeros = calldata.len() as u64 - zeros;
+
+        let estimate = groth16.estimate_onchain_cost(ChainType::Evm);
+        assert_eq!(estimate.unit, CostUnit::Gas);
+        assert_eq!(
+            estimate.amount,
+            EVM_TX_BASE_GAS
+                + EVM_GROTH16_VERIFY_GAS
+                + zeros * EVM_ZERO_BYTE_GAS
+                + nonzeros * EVM_NONZERO_BYTE_GAS
+        );
+    }
+
+    #[test]
+    fn test_export_verifier_report_evm() {
+        use crate::PrivacyEngine;
+
+        let engine = crate::testing::MockEngine::new();
+        let (bytecode, report) = engine.export_verifier_with_report(ChainType::Evm).unwrap();
+        assert_eq!(bytecode, engine.export_verifier(ChainType::Evm).unwrap());
+
+        assert_eq!(report.chain, ChainType::Evm);
+        assert_eq!(report.unit, CostUnit::Gas);
+        assert_eq!(report.proof_bytes, 260);
+        assert_eq!(report.public_input_count, 2);
+        assert!(report.estimated_compute_units > EVM_TX_BASE_GAS + EVM_GROTH16_VERIFY_GAS);
+
+        let solana = VerifierReport::sp1_groth16(ChainType::Solana);
+        assert_eq!(solana.unit, CostUnit::ComputeUnits);
+        assert!(solana.estimated_compute_units > SOLANA_GROTH16_BASE_CU);
+    }
+
+    #[test]
+    fn test_tee_attestation_not_verifiable() {
+        let mut tee = receipt("groth16", 100);
+        tee.proof_type = ProofType::TeeAttestation;
+        let estimate = tee.estimate_onchain_cost(ChainType::Solana);
+
+        assert!(!estimate.onchain_verifiable);
+        assert_eq!(estimate.amount, 140 * SOLANA_CU_PER_BYTE);
+    }
+}
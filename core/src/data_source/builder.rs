@@ -2,6 +2,9 @@
 
 use secrecy::{Secret, ExposeSecret};
 use serde::{Serialize, Deserialize};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::error::BuilderError;
 
 /// Builder for constructing Privacy Engine inputs
 ///
@@ -13,6 +16,8 @@ use serde::{Serialize, Deserialize};
 /// - Secrets are wrapped in `secrecy::Secret` to prevent accidental logging
 /// - Secrets are only exposed during final serialization
 /// - Memory is zeroed when secrets are dropped
+/// - Public data is zeroed on `clear()` and when the builder is dropped, since
+///   it may contain sensitive derived material (e.g., fetched balances)
 ///
 /// ## Example
 ///
@@ -41,6 +46,9 @@ pub struct ZkInputBuilder {
     
     /// User secrets (private keys, passwords, etc.)
     secrets: Vec<Secret<Vec<u8>>>,
+
+    /// Upper bound on the serialized input size, if any
+    max_total_bytes: Option<usize>,
 }
 
 /// Combined input structure for serialization
@@ -53,14 +61,38 @@ struct CombinedInput {
     secrets: Vec<Vec<u8>>,
 }
 
+impl Drop for CombinedInput {
+    fn drop(&mut self) {
+        // Holds exposed copies of every secret while serializing
+        self.secrets.zeroize();
+        self.public_data.zeroize();
+    }
+}
+
 impl ZkInputBuilder {
     /// Create a new input builder
     pub fn new() -> Self {
         Self {
             public_data: Vec::new(),
             secrets: Vec::new(),
+            max_total_bytes: None,
         }
     }
+
+    /// Cap the serialized input size checked by `try_build`
+    ///
+    /// SP1 stdin has practical limits, so callers feeding large documents
+    /// should set this rather than discover the limit inside the prover.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// builder.with_max_total_bytes(16 * 1024 * 1024);
+    /// ```
+    pub fn with_max_total_bytes(&mut self, max: usize) -> &mut Self {
+        self.max_total_bytes = Some(max);
+        self
+    }
     
     /// Add public data from a data provider
     ///
@@ -134,6 +166,15 @@ impl ZkInputBuilder {
     ///
     /// Serialized bytes ready for `PrivacyEngine::prove()`
     ///
+    /// # Panics
+    ///
+    /// Panics with the underlying `BuilderError` if `try_build` fails:
+    ///
+    /// - `BuilderError::EmptyInput` if no public data or secrets were added
+    /// - `BuilderError::TooLarge` if the input exceeds `with_max_total_bytes`
+    ///
+    /// Prefer `try_build` when the input is not under the caller's control.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -141,6 +182,32 @@ impl ZkInputBuilder {
     /// let receipt = privacy_engine.prove(&zk_input)?;
     /// ```
     pub fn build(&self) -> Vec<u8> {
+        self.try_build()
+            .unwrap_or_else(|e| panic!("Failed to build combined input: {e}"))
+    }
+
+    /// Build the final input, checking it is non-empty and within bounds
+    ///
+    /// The size check runs against the computed serialized length, before
+    /// the combined buffer (and the exposed secret copies) are allocated.
+    ///
+    /// # Errors
+    ///
+    /// - `BuilderError::EmptyInput` if no public data or secrets were added
+    /// - `BuilderError::TooLarge` if the input exceeds `max_total_bytes`
+    /// - `BuilderError::Serialization` if bincode encoding fails
+    pub fn try_build(&self) -> Result<Vec<u8>, BuilderError> {
+        if self.public_data.is_empty() && self.secrets.is_empty() {
+            return Err(BuilderError::EmptyInput);
+        }
+
+        let total = self.serialized_len();
+        if let Some(max) = self.max_total_bytes {
+            if total > max {
+                return Err(BuilderError::TooLarge { total, max });
+            }
+        }
+
         // Extract secrets (this is the only place they're exposed)
         let secret_bytes: Vec<Vec<u8>> = self.secrets
             .iter()
@@ -152,9 +219,41 @@ impl ZkInputBuilder {
             secrets: secret_bytes,
         };
         
-        // Serialize to bytes using bincode
+        // Serialize to bytes using bincode; the exposed copies in `combined`
+        // are zeroed when it drops
         bincode::serialize(&combined)
-            .expect("Failed to serialize combined input")
+            .map_err(|e| BuilderError::Serialization(e.to_string()))
+    }
+
+    /// Build the final input into a buffer that is zeroed on drop
+    ///
+    /// The serialized input contains every secret in plain form, so callers
+    /// that hand it straight to the prover should prefer this over `build`
+    /// and let the buffer fall out of scope once proving is done.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let zk_input = builder.build_secret()?;
+    /// let receipt = privacy_engine.prove(&zk_input)?;
+    /// // `zk_input` is wiped here
+    /// ```
+    pub fn build_secret(&self) -> Result<Zeroizing<Vec<u8>>, BuilderError> {
+        self.try_build().map(Zeroizing::new)
+    }
+
+    /// Length of the bincode encoding of `CombinedInput`
+    ///
+    /// Each `Vec` is prefixed with a `u64` length, both for the outer lists
+    /// and for every field inside them.
+    fn serialized_len(&self) -> usize {
+        const LEN_PREFIX: usize = std::mem::size_of::<u64>();
+        let public: usize = self.public_data.iter().map(|d| LEN_PREFIX + d.len()).sum();
+        let secrets: usize = self.secrets
+            .iter()
+            .map(|s| LEN_PREFIX + s.expose_secret().len())
+            .sum();
+        2 * LEN_PREFIX + public + secrets
     }
     
     /// Get the number of public data fields
@@ -171,10 +270,26 @@ impl ZkInputBuilder {
     ///
     /// Useful for reusing the builder.
     pub fn clear(&mut self) -> &mut Self {
+        self.wipe_public_data();
         self.public_data.clear();
         self.secrets.clear();
         self
     }
+
+    /// Overwrite every public data buffer with zeros in place
+    ///
+    /// Secrets don't need this: `Secret` zeroizes its contents on drop.
+    fn wipe_public_data(&mut self) {
+        for data in &mut self.public_data {
+            data.as_mut_slice().zeroize();
+        }
+    }
+}
+
+impl Drop for ZkInputBuilder {
+    fn drop(&mut self) {
+        self.wipe_public_data();
+    }
 }
 
 #[cfg(test)]
@@ -222,6 +337,21 @@ mod tests {
         assert_eq!(combined.public_data[0], vec![1, 2, 3]);
         assert_eq!(combined.secrets[0], vec![4, 5, 6]);
     }
+
+    #[test]
+    fn test_build_secret() {
+        let mut builder = ZkInputBuilder::new();
+        builder.add_public_data(vec![1, 2, 3]);
+        builder.add_secret(vec![0xCA, 0xFE, 0xBA, 0xBE]);
+
+        let input = builder.build_secret().unwrap();
+        assert_eq!(*input, builder.build());
+
+        // Secret bytes are readable through the wrapper until it drops
+        assert!(input.windows(4).any(|w| w == [0xCA, 0xFE, 0xBA, 0xBE]));
+        let combined: CombinedInput = bincode::deserialize(&input).unwrap();
+        assert_eq!(combined.secrets[0], vec![0xCA, 0xFE, 0xBA, 0xBE]);
+    }
     
     #[test]
     fn test_batch_operations() {
@@ -253,6 +383,65 @@ mod tests {
         assert_eq!(builder.secrets_count(), 0);
     }
     
+    #[test]
+    fn test_wipe_public_data_zeroizes_in_place() {
+        let mut builder = ZkInputBuilder::new();
+        builder.add_public_data(vec![0xDE, 0xAD, 0xBE, 0xEF]);
+        builder.add_public_data(vec![1, 2, 3]);
+
+        // This is the routine `Drop` and `clear()` run before releasing memory
+        builder.wipe_public_data();
+
+        assert_eq!(builder.public_data_count(), 2);
+        assert!(builder.public_data.iter().all(|d| d.iter().all(|&b| b == 0)));
+        assert_eq!(builder.public_data[0].len(), 4);
+    }
+
+    #[test]
+    fn test_serialized_len_matches_bincode() {
+        let mut builder = ZkInputBuilder::new();
+        builder.add_public_data(vec![1, 2, 3]);
+        builder.add_public_data(vec![]);
+        builder.add_secret(vec![4, 5, 6, 7]);
+
+        assert_eq!(builder.serialized_len(), builder.build().len());
+    }
+
+    #[test]
+    fn test_try_build_too_large() {
+        let mut builder = ZkInputBuilder::new();
+        builder.add_public_data(vec![0u8; 64]);
+        builder.add_secret(vec![1u8; 64]);
+        builder.with_max_total_bytes(100);
+
+        match builder.try_build() {
+            Err(BuilderError::TooLarge { total, max }) => {
+                assert_eq!(total, 8 + 8 + 64 + 8 + 8 + 64);
+                assert_eq!(max, 100);
+            }
+            other => panic!("expected TooLarge, got {:?}", other),
+        }
+
+        builder.with_max_total_bytes(160);
+        assert!(builder.try_build().is_ok());
+    }
+
+    #[test]
+    fn test_try_build_empty() {
+        let builder = ZkInputBuilder::new();
+        assert!(matches!(builder.try_build(), Err(BuilderError::EmptyInput)));
+
+        let mut builder = ZkInputBuilder::new();
+        builder.add_secret(vec![1]);
+        assert!(builder.try_build().is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to build combined input: No public data or secrets were added")]
+    fn test_build_empty_panics_with_cause() {
+        ZkInputBuilder::new().build();
+    }
+
     #[test]
     fn test_chaining() {
         let input = ZkInputBuilder::new()
@@ -0,0 +1,426 @@
+//! DECO-style three-party zkTLS provider
+//!
+//! In the recorded model (`RecordedTlsProof`) a single notary witnesses the
+//! session and signs its transcript, so the notary must be trusted. DECO
+//! instead splits the TLS MAC key between the prover and the verifier during
+//! a three-party handshake:
+//!
+//! 1. The handshake leaves the prover with `prover_key_share` and the
+//!    verifier with `verifier_key_share`. The session MAC key is their XOR,
+//!    so neither party alone can authenticate a record.
+//! 2. The prover receives the server's response and its MAC, then commits to
+//!    its key share and the response before learning the verifier's share.
+//! 3. The verifier reveals its share and signs
+//!    `(domain, commitment, verifier_key_share)` with its Ed25519 key. Anyone
+//!    holding the `DecoSession` and the verifier's public key can check that
+//!    signature, rebuild the MAC key, check the commitment, and check the
+//!    server's MAC over the response.
+//!
+//! Because the prover committed before it could compute MACs, it cannot swap
+//! in a forged response afterwards. The verifier's signature binds the
+//! session to a real handshake: without it a prover could pick both shares
+//! itself and fabricate a transcript that is merely self-consistent. The
+//! verifier only ever sees the commitment, never the response.
+//!
+//! The network side (handshake, request, share reveal) sits behind
+//! `DecoTransport`, so the protocol checks can run offline in tests.
+
+use async_trait::async_trait;
+use ed25519_dalek::{Signature, VerifyingKey};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use super::error::DataError;
+use super::http::select_json_field;
+use super::provider::DataProvider;
+use super::zktls::{RecordedTlsProof, ZkTlsError};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Domain separator for key-share commitments
+const COMMITMENT_DOMAIN: &[u8] = b"upe-deco-commit-v1";
+
+/// Domain separator for the verifier's signature over its revealed share
+const REVEAL_DOMAIN: &[u8] = b"upe-deco-reveal-v1";
+
+/// A TLS response record as seen by the prover: plaintext plus the server's MAC
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecoRecord {
+    /// Decrypted response body
+    pub body: Vec<u8>,
+
+    /// HMAC-SHA256 of `body` under the session MAC key
+    pub mac: [u8; 32],
+}
+
+/// Network side of a DECO session
+///
+/// Implementations drive the three-party handshake with the server and the
+/// verifier. The verifier must refuse to reveal its share until it has
+/// received the prover's commitment.
+#[async_trait]
+pub trait DecoTransport: Send + Sync {
+    /// Run the three-party handshake for `domain` and return the prover's MAC key share
+    async fn handshake(&self, domain: &str) -> Result<[u8; 32], DataError>;
+
+    /// Send the request for `source` and return the response record
+    async fn request(&self, source: &str) -> Result<DecoRecord, DataError>;
+
+    /// Hand the prover's commitment to the verifier and receive its signed key share
+    async fn reveal_verifier_share(&self, commitment: &[u8; 32]) -> Result<VerifierReveal, DataError>;
+}
+
+/// The verifier's key share, signed together with the commitment it answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifierReveal {
+    /// Verifier's half of the MAC key
+    pub key_share: [u8; 32],
+
+    /// Hex Ed25519 signature over `DecoSession::reveal_message`
+    pub signature: String,
+}
+
+/// Transcript of a completed DECO session
+///
+/// `DecoProvider::verify_session` needs only the verifier's public key to check it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecoSession {
+    /// Server domain the session was established with
+    pub domain: String,
+
+    /// Response body the prover is attesting to
+    pub response: Vec<u8>,
+
+    /// Server MAC over `response`
+    pub response_mac: [u8; 32],
+
+    /// Prover's half of the MAC key
+    pub prover_key_share: [u8; 32],
+
+    /// Verifier's half of the MAC key, revealed after the commitment
+    pub verifier_key_share: [u8; 32],
+
+    /// Prover's commitment made before `verifier_key_share` was revealed
+    pub commitment: [u8; 32],
+
+    /// Verifier's hex Ed25519 signature over `reveal_message`
+    pub verifier_signature: String,
+}
+
+impl DecoSession {
+    /// Compute the prover's commitment to its key share and the received record.
+    ///
+    /// `sha256(COMMITMENT_DOMAIN || len(domain) || domain || prover_key_share || sha256(response) || response_mac)`
+    pub fn commit(domain: &str, prover_key_share: &[u8; 32], response: &[u8], response_mac: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(COMMITMENT_DOMAIN);
+        hasher.update((domain.len() as u64).to_le_bytes());
+        hasher.update(domain.as_bytes());
+        hasher.update(prover_key_share);
+        hasher.update(Sha256::digest(response));
+        hasher.update(response_mac);
+        hasher.finalize().into()
+    }
+
+    /// Digest the verifier signs when revealing its share.
+    ///
+    /// `sha256(REVEAL_DOMAIN || len(domain) || domain || commitment || verifier_key_share)`
+    pub fn reveal_message(domain: &str, commitment: &[u8; 32], verifier_key_share: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(REVEAL_DOMAIN);
+        hasher.update((domain.len() as u64).to_le_bytes());
+        hasher.update(domain.as_bytes());
+        hasher.update(commitment);
+        hasher.update(verifier_key_share);
+        hasher.finalize().into()
+    }
+
+    /// Combine both key shares into the session MAC key
+    pub fn mac_key(&self) -> [u8; 32] {
+        combine_shares(&self.prover_key_share, &self.verifier_key_share)
+    }
+}
+
+/// XOR two key shares into the full MAC key
+pub fn combine_shares(prover_share: &[u8; 32], verifier_share: &[u8; 32]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    for (k, (p, v)) in key.iter_mut().zip(prover_share.iter().zip(verifier_share)) {
+        *k = p ^ v;
+    }
+    key
+}
+
+/// Compute the record MAC for `body` under `key`
+pub fn record_mac(key: &[u8; 32], body: &[u8]) -> [u8; 32] {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(body);
+    mac.finalize().into_bytes().into()
+}
+
+/// Data provider backed by DECO-style three-party sessions
+///
+/// ## Example
+///
+/// ```ignore
+/// let provider = DecoProvider::new(my_transport, verifier_key);
+/// let (balance, session) = provider
+///     .fetch_with_session("https://api.bank.com/account/123", "data.balance")
+///     .await?;
+/// provider.verify_session(&session)?;
+/// ```
+pub struct DecoProvider<T: DecoTransport> {
+    transport: T,
+    verifier_key: VerifyingKey,
+}
+
+impl<T: DecoTransport> DecoProvider<T> {
+    /// Create a provider over the given transport, trusting sessions signed by `verifier_key`
+    pub fn new(transport: T, verifier_key: VerifyingKey) -> Self {
+        Self { transport, verifier_key }
+    }
+
+    /// Fetch a JSON field and return it with the session transcript that attests it
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataError::TlsVerificationFailed` if the session doesn't
+    /// verify, plus the usual transport and field-selection errors
+    pub async fn fetch_with_session(&self, source: &str, query: &str) -> Result<(Vec<u8>, DecoSession), DataError> {
+        let url = reqwest::Url::parse(source)
+            .map_err(|e| DataError::InvalidQuery(format!("Invalid URL '{}': {}", source, e)))?;
+        let domain = url
+            .host_str()
+            .ok_or_else(|| DataError::InvalidQuery(format!("URL has no host: {}", source)))?
+            .to_string();
+
+        let prover_key_share = self.transport.handshake(&domain).await?;
+        let record = self.transport.request(source).await?;
+        let commitment = DecoSession::commit(&domain, &prover_key_share, &record.body, &record.mac);
+        let reveal = self.transport.reveal_verifier_share(&commitment).await?;
+
+        let session = DecoSession {
+            domain,
+            response: record.body,
+            response_mac: record.mac,
+            prover_key_share,
+            verifier_key_share: reveal.key_share,
+            commitment,
+            verifier_signature: reveal.signature,
+        };
+        self.verify_session(&session)?;
+
+        let json: Value = serde_json::from_slice(&session.response)
+            .map_err(DataError::JsonError)?;
+        let field = select_json_field(&json, query)?;
+        Ok((field, session))
+    }
+
+    /// Check a session's verifier signature, key-share commitment and response MAC
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataError::TlsVerificationFailed` if the verifier didn't sign
+    /// the revealed share, the commitment doesn't match the transcript, or
+    /// the MAC doesn't authenticate the response
+    pub fn verify_session(&self, session: &DecoSession) -> Result<(), DataError> {
+        let signature: [u8; 64] = hex::decode(&session.verifier_signature)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| DataError::TlsVerificationFailed("Malformed DECO verifier signature".into()))?;
+        let message = DecoSession::reveal_message(&session.domain, &session.commitment, &session.verifier_key_share);
+        self.verifier_key
+            .verify_strict(&message, &Signature::from_bytes(&signature))
+            .map_err(|_| DataError::TlsVerificationFailed("DECO verifier signature invalid".into()))?;
+
+        let expected = DecoSession::commit(
+            &session.domain,
+            &session.prover_key_share,
+            &session.response,
+            &session.response_mac,
+        );
+        if expected != session.commitment {
+            return Err(DataError::TlsVerificationFailed("DECO key share commitment mismatch".into()));
+        }
+
+        let mut mac = HmacSha256::new_from_slice(&session.mac_key()).expect("HMAC accepts any key length");
+        mac.update(&session.response);
+        mac.verify_slice(&session.response_mac)
+            .map_err(|_| DataError::TlsVerificationFailed("DECO response MAC mismatch".into()))
+    }
+}
+
+#[async_trait]
+impl<T: DecoTransport> DataProvider for DecoProvider<T> {
+    async fn fetch(&self, source: &str, query: &str) -> Result<Vec<u8>, DataError> {
+        self.fetch_with_session(source, query).await.map(|(field, _)| field)
+    }
+
+    fn verify_tls_proof(&self, _proof: &RecordedTlsProof) -> Result<(), ZkTlsError> {
+        // Single-notary proofs are exactly what DECO avoids trusting
+        Err(ZkTlsError::SignatureInvalid(
+            "DecoProvider verifies DECO sessions, not recorded notary proofs".into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use std::sync::Mutex;
+
+    const PROVER_SHARE: [u8; 32] = [0x11; 32];
+    const VERIFIER_SHARE: [u8; 32] = [0x5A; 32];
+    const BODY: &[u8] = br#"{"data":{"balance":1000}}"#;
+
+    fn verifier() -> SigningKey {
+        SigningKey::from_bytes(&[0x77; 32])
+    }
+
+    /// In-process server + verifier. Optionally alters the body in transit.
+    struct MockDeco {
+        tamper_in_transit: bool,
+        domain: Mutex<String>,
+        commitment_seen: Mutex<Option<[u8; 32]>>,
+    }
+
+    impl MockDeco {
+        fn new(tamper_in_transit: bool) -> Self {
+            Self { tamper_in_transit, domain: Mutex::new(String::new()), commitment_seen: Mutex::new(None) }
+        }
+    }
+
+    fn provider(transport: MockDeco) -> DecoProvider<MockDeco> {
+        DecoProvider::new(transport, verifier().verifying_key())
+    }
+
+    #[async_trait]
+    impl DecoTransport for MockDeco {
+        async fn handshake(&self, domain: &str) -> Result<[u8; 32], DataError> {
+            *self.domain.lock().unwrap() = domain.to_string();
+            Ok(PROVER_SHARE)
+        }
+
+        async fn request(&self, _source: &str) -> Result<DecoRecord, DataError> {
+            let mac = record_mac(&combine_shares(&PROVER_SHARE, &VERIFIER_SHARE), BODY);
+            let body = if self.tamper_in_transit {
+                br#"{"data":{"balance":9999}}"#.to_vec()
+            } else {
+                BODY.to_vec()
+            };
+            Ok(DecoRecord { body, mac })
+        }
+
+        async fn reveal_verifier_share(&self, commitment: &[u8; 32]) -> Result<VerifierReveal, DataError> {
+            *self.commitment_seen.lock().unwrap() = Some(*commitment);
+            let message = DecoSession::reveal_message(&self.domain.lock().unwrap(), commitment, &VERIFIER_SHARE);
+            Ok(VerifierReveal {
+                key_share: VERIFIER_SHARE,
+                signature: hex::encode(verifier().sign(&message).to_bytes()),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_valid_session_passes() {
+        let provider = provider(MockDeco::new(false));
+        let (field, session) = provider
+            .fetch_with_session("https://bank.example/account", "data.balance")
+            .await
+            .unwrap();
+
+        assert_eq!(field, b"1000");
+        assert_eq!(session.domain, "bank.example");
+        assert_eq!(provider.transport.commitment_seen.lock().unwrap().unwrap(), session.commitment);
+        assert!(provider.verify_session(&session).is_ok());
+        assert_eq!(provider.fetch("https://bank.example/account", "data.balance").await.unwrap(), b"1000");
+    }
+
+    #[tokio::test]
+    async fn test_tampered_response_fails() {
+        let provider = provider(MockDeco::new(false));
+        let (_, session) = provider
+            .fetch_with_session("https://bank.example/account", "")
+            .await
+            .unwrap();
+
+        // Prover edits the response and recomputes its commitment
+        let mut forged = session.clone();
+        forged.response = br#"{"data":{"balance":9999}}"#.to_vec();
+        forged.commitment = DecoSession::commit(
+            &forged.domain,
+            &forged.prover_key_share,
+            &forged.response,
+            &forged.response_mac,
+        );
+        // The new commitment was never signed by the verifier
+        let err = provider.verify_session(&forged).unwrap_err();
+        assert!(err.to_string().contains("verifier signature invalid"));
+
+        // Editing the response without recommitting breaks the commitment
+        let mut stale = session;
+        stale.response.push(b' ');
+        let err = provider.verify_session(&stale).unwrap_err();
+        assert!(err.to_string().contains("commitment mismatch"));
+    }
+
+    #[tokio::test]
+    async fn test_tampered_in_transit_fails_fetch() {
+        let provider = provider(MockDeco::new(true));
+        let result = provider.fetch("https://bank.example/account", "data.balance").await;
+        assert!(matches!(result, Err(DataError::TlsVerificationFailed(msg)) if msg.contains("MAC mismatch")));
+    }
+
+    #[test]
+    fn test_self_fabricated_session_rejected() {
+        // Prover picks both shares, MACs its own body and commits: the
+        // transcript is internally consistent but no verifier took part
+        let domain = "bank.example";
+        let body = br#"{"data":{"balance":9999}}"#.to_vec();
+        let verifier_key_share = [0x42; 32];
+        let response_mac = record_mac(&combine_shares(&PROVER_SHARE, &verifier_key_share), &body);
+        let commitment = DecoSession::commit(domain, &PROVER_SHARE, &body, &response_mac);
+        let mut session = DecoSession {
+            domain: domain.to_string(),
+            response: body,
+            response_mac,
+            prover_key_share: PROVER_SHARE,
+            verifier_key_share,
+            commitment,
+            verifier_signature: String::new(),
+        };
+        let provider = provider(MockDeco::new(false));
+        let err = provider.verify_session(&session).unwrap_err();
+        assert!(err.to_string().contains("Malformed DECO verifier signature"));
+
+        // Signing with a key of the prover's own choosing doesn't help
+        let message = DecoSession::reveal_message(domain, &commitment, &verifier_key_share);
+        session.verifier_signature = hex::encode(SigningKey::from_bytes(&[0x99; 32]).sign(&message).to_bytes());
+        let err = provider.verify_session(&session).unwrap_err();
+        assert!(err.to_string().contains("verifier signature invalid"));
+    }
+
+    #[tokio::test]
+    async fn test_verifier_signature_binds_domain_and_share() {
+        let provider = provider(MockDeco::new(false));
+        let (_, session) = provider
+            .fetch_with_session("https://bank.example/account", "")
+            .await
+            .unwrap();
+
+        let mut moved = session.clone();
+        moved.domain = "other.example".into();
+        moved.commitment = DecoSession::commit(
+            &moved.domain,
+            &moved.prover_key_share,
+            &moved.response,
+            &moved.response_mac,
+        );
+        assert!(matches!(provider.verify_session(&moved), Err(DataError::TlsVerificationFailed(_))));
+
+        let mut swapped = session;
+        swapped.verifier_key_share = [0x42; 32];
+        assert!(matches!(provider.verify_session(&swapped), Err(DataError::TlsVerificationFailed(_))));
+    }
+}
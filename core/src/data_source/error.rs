@@ -6,12 +6,19 @@ use thiserror::Error;
 #[derive(Debug, Error)]
 pub enum DataError {
     /// HTTP request failed
-    #[error("HTTP request failed: {0}")]
-    HttpError(String),
+    ///
+    /// `source` holds the underlying error (transport, I/O, proof check) when
+    /// there is one, so `Error::source()` chains reach it.
+    #[error("HTTP request failed: {message}")]
+    HttpError {
+        message: String,
+        #[source]
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
     
     /// JSON parsing failed
     #[error("JSON parsing failed: {0}")]
-    JsonError(String),
+    JsonError(#[source] serde_json::Error),
     
     /// Requested field not found in JSON
     #[error("Field not found: {0}")]
@@ -21,10 +28,18 @@ pub enum DataError {
     #[error("TLS verification failed: {0}")]
     TlsVerificationFailed(String),
     
+    /// Server certificate did not match the provider's pin
+    #[error("Certificate pin mismatch: expected {expected}, got {got}")]
+    CertPinMismatch { expected: String, got: String },
+    
     /// Invalid query syntax
     #[error("Invalid query: {0}")]
     InvalidQuery(String),
     
+    /// Request exceeded the provider's timeout
+    #[error("Request timed out after {secs}s")]
+    Timeout { secs: u64 },
+    
     /// Network error
     #[error("Network error: {0}")]
     NetworkError(#[from] reqwest::Error),
@@ -33,3 +48,57 @@ pub enum DataError {
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
 }
+
+impl DataError {
+    /// HTTP failure with no underlying error (e.g. a non-success status)
+    pub fn http(message: impl Into<String>) -> Self {
+        DataError::HttpError { message: message.into(), source: None }
+    }
+
+    /// HTTP failure caused by `source`
+    pub fn http_with_source(
+        message: impl Into<String>,
+        source: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        DataError::HttpError { message: message.into(), source: Some(Box::new(source)) }
+    }
+}
+
+/// Errors produced by `ZkInputBuilder::try_build`
+#[derive(Debug, Error)]
+pub enum BuilderError {
+    /// Neither public data nor secrets were added
+    #[error("No public data or secrets were added")]
+    EmptyInput,
+
+    /// Serialized input would exceed the configured `max_total_bytes`
+    #[error("Input too large: {total} bytes exceeds limit of {max} bytes")]
+    TooLarge { total: usize, max: usize },
+
+    /// Bincode encoding of the combined input failed
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_source_chain_preserved() {
+        let json_err = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+        let expected = json_err.to_string();
+        let err = DataError::JsonError(json_err);
+        assert_eq!(err.source().unwrap().to_string(), expected);
+        assert!(err.to_string().starts_with("JSON parsing failed: "));
+
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "missing fixture");
+        let err = DataError::http_with_source("Failed to read fixture", io_err);
+        assert_eq!(err.to_string(), "HTTP request failed: Failed to read fixture");
+        let source = err.source().unwrap();
+        assert!(source.downcast_ref::<std::io::Error>().is_some());
+
+        assert!(DataError::http("HTTP 404 Not Found").source().is_none());
+    }
+}
@@ -3,8 +3,12 @@
 use async_trait::async_trait;
 use reqwest::Client;
 use serde_json::Value;
+use std::time::Duration;
 use super::error::DataError;
+use super::pinning::{pin_mismatch, pinned_tls_config};
 use super::provider::DataProvider;
+use super::streaming::{find_json_path, found_field, ChunkReader};
+use super::zktls::{RecordedTlsProof, VerifyPolicy};
 
 // TRUST ANCHOR: The Public Key of our Trusted Local Notary (from capture_zktls)
 // This strictly enforces that only proofs signed by this specific key are accepted.
@@ -20,6 +24,8 @@ const TRUSTED_NOTARY_PUBKEY: &str = "8b5f297b8a3f383c2cdc68d0f10f99aec1bc1448408
 /// - `"field"` - Top-level field
 /// - `"parent.child"` - Nested field access
 /// - `"array[0]"` - Array indexing
+/// - `"array[-1]"` - Negative index, counted from the end
+/// - `"users[*].age"` - Wildcard, collects the sub-field from every element
 /// - `"data.items[2].name"` - Complex nested path
 ///
 /// ## Example
@@ -34,16 +40,134 @@ const TRUSTED_NOTARY_PUBKEY: &str = "8b5f297b8a3f383c2cdc68d0f10f99aec1bc1448408
 pub struct HttpProvider {
     /// HTTP client for making requests
     client: Client,
+
+    /// Per-request timeout applied by `client`
+    timeout: Duration,
+
+    /// Retry policy for transient failures
+    retry: RetryConfig,
+
+    /// Expected SHA-256 (hex) of the server's leaf certificate, if pinned
+    pinned_cert: Option<String>,
+}
+
+/// Default per-request timeout for `HttpProvider`
+pub const DEFAULT_HTTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Retry policy for `HttpProvider` requests
+///
+/// Only connection errors and 5xx responses are retried. Attempt `n` (from 0)
+/// waits `base_delay * 2^n`, jittered down by up to half, before retrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+
+    /// Delay before the first retry
+    pub base_delay: Duration,
+}
+
+impl RetryConfig {
+    /// Single attempt, no retries (the `HttpProvider` default)
+    pub const NONE: RetryConfig = RetryConfig { max_retries: 0, base_delay: Duration::ZERO };
+
+    /// Jittered delay before retry number `attempt` (0-based)
+    fn delay(&self, attempt: u32) -> Duration {
+        use std::hash::{BuildHasher, Hasher};
+
+        let backoff = self.base_delay.saturating_mul(1u32 << attempt.min(16));
+        // `RandomState` is freshly keyed per call, which is enough entropy
+        // to keep concurrent clients from retrying in lockstep
+        let random = std::collections::hash_map::RandomState::new().build_hasher().finish();
+        let half = backoff / 2;
+        half + half.mul_f64((random % 1000) as f64 / 1000.0)
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self { max_retries: 3, base_delay: Duration::from_millis(200) }
+    }
 }
 
 impl HttpProvider {
     /// Create a new HTTP provider
     pub fn new() -> Self {
+        Self::with_timeout(DEFAULT_HTTP_TIMEOUT)
+    }
+
+    /// Create a new HTTP provider with a custom per-request timeout
+    ///
+    /// Requests exceeding it fail with `DataError::Timeout`.
+    pub fn with_timeout(timeout: Duration) -> Self {
         Self {
-            client: Client::builder()
-                .timeout(std::time::Duration::from_secs(30))
-                .build()
-                .expect("Failed to create HTTP client"),
+            client: build_client(timeout, None),
+            timeout,
+            retry: RetryConfig::NONE,
+            pinned_cert: None,
+        }
+    }
+
+    /// Only accept servers presenting the pinned leaf certificate
+    ///
+    /// `cert_sha256` is the hex SHA-256 of the certificate's DER encoding
+    /// (the same digest `openssl x509 -outform der | sha256sum` prints).
+    /// The pin is enforced in the TLS handshake, so a MITM never receives the
+    /// request and cannot feed fabricated data into the notarization flow.
+    /// Plain-HTTP sources present no certificate and are rejected before
+    /// connecting. `fetch_verified` additionally requires the proof's
+    /// `cert_chain_hash` to be the pin.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let provider = HttpProvider::new().with_pinned_cert(BANK_CERT_SHA256);
+    /// ```
+    pub fn with_pinned_cert(mut self, cert_sha256: impl Into<String>) -> Self {
+        let pin = cert_sha256.into().to_ascii_lowercase();
+        self.client = build_client(self.timeout, Some(&pin));
+        self.pinned_cert = Some(pin);
+        self
+    }
+
+    /// Retry transient failures according to `retry`
+    ///
+    /// Connection errors and 5xx responses are retried with exponential
+    /// backoff; 4xx responses, timeouts and selection errors are not.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let provider = HttpProvider::new().with_retry(RetryConfig {
+    ///     max_retries: 3,
+    ///     base_delay: Duration::from_millis(200),
+    /// });
+    /// ```
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Reject sources that can't present the pinned certificate
+    fn check_pinned_scheme(&self, source: &str) -> Result<(), DataError> {
+        match &self.pinned_cert {
+            Some(expected) if !source.starts_with("https://") => Err(DataError::CertPinMismatch {
+                expected: expected.clone(),
+                got: "no certificate".to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Map a reqwest error, surfacing timeouts as `DataError::Timeout` and
+    /// pin failures as `DataError::CertPinMismatch`
+    fn request_error(&self, context: &str, e: reqwest::Error) -> DataError {
+        if let Some(mismatch) = self.pinned_cert.as_deref().and_then(|pin| pin_mismatch(pin, &e)) {
+            mismatch
+        } else if e.is_timeout() {
+            DataError::Timeout { secs: self.timeout.as_secs() }
+        } else {
+            DataError::http_with_source(context, e)
         }
     }
     
@@ -62,61 +186,234 @@ impl HttpProvider {
     ///
     /// Returns `DataError::FieldNotFound` if the path doesn't exist
     /// Returns `DataError::InvalidQuery` if the path syntax is invalid
-    fn select_json_field(&self, json: &Value, path: &str) -> Result<Vec<u8>, DataError> {
-        if path.is_empty() {
-            // Empty path returns the entire JSON
-            return serde_json::to_vec(json)
-                .map_err(|e| DataError::SerializationError(e));
+    pub(crate) fn select_json_field(&self, json: &Value, path: &str) -> Result<Vec<u8>, DataError> {
+        select_json_field(json, path)
+    }
+}
+
+/// Build the reqwest client, trusting only the `pin`ned certificate if given
+fn build_client(timeout: Duration, pin: Option<&str>) -> Client {
+    let mut builder = Client::builder().timeout(timeout);
+    if let Some(pin) = pin {
+        builder = builder.use_preconfigured_tls(pinned_tls_config(pin));
+    }
+    builder.build().expect("Failed to create HTTP client")
+}
+
+/// Select a field from JSON using the `HttpProvider` path syntax.
+///
+/// Shared by every provider that returns JSON fields, so query semantics are
+/// identical across transports. Array segments accept a non-negative index,
+/// a negative index counted from the end (`items[-1]` is the last element),
+/// or `[*]`, which applies the rest of the path to every element and returns
+/// the results as one JSON array (e.g. `data.users[*].age`).
+pub(crate) fn select_json_field(json: &Value, path: &str) -> Result<Vec<u8>, DataError> {
+    if path.is_empty() {
+        // Empty path returns the entire JSON
+        return serde_json::to_vec(json)
+            .map_err(DataError::SerializationError);
+    }
+    
+    let parts: Vec<&str> = path.split('.').collect();
+    let selected = select_path(json, &parts)?;
+    
+    // Serialize the selected value to bytes
+    serde_json::to_vec(&selected)
+        .map_err(DataError::SerializationError)
+}
+
+/// Array segment of a path: `[n]`, `[-n]` or `[*]`
+enum ArrayIndex {
+    FromStart(usize),
+    FromEnd(usize),
+    All,
+}
+
+/// Walk `parts` from `current`, fanning out at each `[*]`
+fn select_path(current: &Value, parts: &[&str]) -> Result<Value, DataError> {
+    let Some((part, rest)) = parts.split_first() else {
+        return Ok(current.clone());
+    };
+    
+    // Check if this part has array indexing: "field[0]"
+    let Some(bracket_pos) = part.find('[') else {
+        // Simple field access
+        let next = current.get(part)
+            .ok_or_else(|| DataError::FieldNotFound(
+                format!("Field '{}' not found", part)
+            ))?;
+        return select_path(next, rest);
+    };
+    
+    // Extract field name and index
+    let field_name = &part[..bracket_pos];
+    let bracket = &part[bracket_pos..];
+    
+    // Parse index from "[0]", "[-1]" or "[*]"
+    if !bracket.ends_with(']') {
+        return Err(DataError::InvalidQuery(
+            format!("Invalid array syntax: {}", part)
+        ));
+    }
+    
+    let index_str = &bracket[1..bracket.len()-1];
+    let index = if index_str == "*" {
+        ArrayIndex::All
+    } else if let Some(from_end) = index_str.strip_prefix('-') {
+        ArrayIndex::FromEnd(parse_index(from_end, index_str)?)
+    } else {
+        ArrayIndex::FromStart(parse_index(index_str, index_str)?)
+    };
+    
+    // Navigate to field (if not empty)
+    let mut array = current;
+    if !field_name.is_empty() {
+        array = current.get(field_name)
+            .ok_or_else(|| DataError::FieldNotFound(
+                format!("Field '{}' not found", field_name)
+            ))?;
+    }
+    
+    // Navigate to array element(s)
+    let element = match index {
+        ArrayIndex::FromStart(i) => array.get(i),
+        ArrayIndex::FromEnd(n) => array.as_array()
+            .filter(|items| n >= 1 && n <= items.len())
+            .map(|items| &items[items.len() - n]),
+        ArrayIndex::All => {
+            let items = array.as_array()
+                .ok_or_else(|| DataError::FieldNotFound(
+                    format!("Field '{}' is not an array", part)
+                ))?;
+            return items.iter()
+                .map(|item| select_path(item, rest))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array);
         }
-        
-        let parts: Vec<&str> = path.split('.').collect();
-        let mut current = json;
-        
-        for part in parts {
-            // Check if this part has array indexing: "field[0]"
-            if let Some(bracket_pos) = part.find('[') {
-                // Extract field name and index
-                let field_name = &part[..bracket_pos];
-                let rest = &part[bracket_pos..];
-                
-                // Parse index from "[0]"
-                if !rest.ends_with(']') {
-                    return Err(DataError::InvalidQuery(
-                        format!("Invalid array syntax: {}", part)
-                    ));
+    };
+    let element = element
+        .ok_or_else(|| DataError::FieldNotFound(
+            format!("Array index {} out of bounds", index_str)
+        ))?;
+    
+    select_path(element, rest)
+}
+
+fn parse_index(digits: &str, index_str: &str) -> Result<usize, DataError> {
+    digits.parse()
+        .map_err(|_| DataError::InvalidQuery(
+            format!("Invalid array index: {}", index_str)
+        ))
+}
+
+impl HttpProvider {
+    /// Fetch a JSON field and verify it against a recorded TLS proof
+    ///
+    /// The response body actually received is checked against `proof` with
+    /// `RecordedTlsProof::verify` (domain taken from `source`, current system
+    /// time), and the field is only returned if verification succeeds. This is
+    /// the secure fetch path: the proof must attest to the live response.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - HTTPS URL to fetch
+    /// * `query` - JSON path of the field to return
+    /// * `proof` - Notarized proof of the TLS session
+    /// * `cert_chain_pem` - Server certificate chain recorded with the proof
+    /// * `policy` - Freshness and certificate checks to apply
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataError::TlsVerificationFailed` if the proof doesn't match
+    /// the live response, `DataError::CertPinMismatch` if the provider is
+    /// pinned and the proof's `cert_chain_hash` isn't the pin, plus the usual
+    /// fetch/selection errors
+    pub async fn fetch_verified(
+        &self,
+        source: &str,
+        query: &str,
+        proof: &RecordedTlsProof,
+        cert_chain_pem: &[u8],
+        policy: VerifyPolicy,
+    ) -> Result<Vec<u8>, DataError> {
+        let url = reqwest::Url::parse(source)
+            .map_err(|e| DataError::InvalidQuery(format!("Invalid URL '{}': {}", source, e)))?;
+        let domain = url
+            .host_str()
+            .ok_or_else(|| DataError::InvalidQuery(format!("URL has no host: {}", source)))?;
+
+        // The proof must attest to the same certificate the connection is pinned to
+        if let Some(pin) = &self.pinned_cert {
+            if !proof.cert_chain_hash.eq_ignore_ascii_case(pin) {
+                return Err(DataError::CertPinMismatch {
+                    expected: pin.clone(),
+                    got: proof.cert_chain_hash.clone(),
+                });
+            }
+        }
+
+        let body = self.fetch_body(source).await?;
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        proof
+            .verify(domain, cert_chain_pem, &body, now, &policy)
+            .map_err(|e| DataError::TlsVerificationFailed(e.to_string()))?;
+
+        let json: Value = serde_json::from_slice(&body)
+            .map_err(DataError::JsonError)?;
+        self.select_json_field(&json, query)
+    }
+
+    /// GET `source`, retrying per `self.retry`, and return a successful response
+    async fn get_with_retry(&self, source: &str) -> Result<reqwest::Response, DataError> {
+        self.check_pinned_scheme(source)?;
+
+        let mut attempt = 0;
+        loop {
+            let retryable = match self.client.get(source).send().await {
+                Ok(response) => {
+                    if response.status().is_success() {
+                        return Ok(response);
+                    }
+                    let status = response.status();
+                    let error = DataError::http(
+                        format!("HTTP {} {}", status.as_u16(), status.canonical_reason().unwrap_or(""))
+                    );
+                    if !status.is_server_error() {
+                        return Err(error);
+                    }
+                    error
                 }
-                
-                let index_str = &rest[1..rest.len()-1];
-                let index: usize = index_str.parse()
-                    .map_err(|_| DataError::InvalidQuery(
-                        format!("Invalid array index: {}", index_str)
-                    ))?;
-                
-                // Navigate to field (if not empty)
-                if !field_name.is_empty() {
-                    current = current.get(field_name)
-                        .ok_or_else(|| DataError::FieldNotFound(
-                            format!("Field '{}' not found", field_name)
-                        ))?;
+                Err(e) => {
+                    let connect = e.is_connect();
+                    let error = self.request_error("Request failed", e);
+                    // A pin mismatch also fails at connect time, but won't go away
+                    if !connect || matches!(error, DataError::CertPinMismatch { .. }) {
+                        return Err(error);
+                    }
+                    error
                 }
-                
-                // Navigate to array element
-                current = current.get(index)
-                    .ok_or_else(|| DataError::FieldNotFound(
-                        format!("Array index {} out of bounds", index)
-                    ))?;
-            } else {
-                // Simple field access
-                current = current.get(part)
-                    .ok_or_else(|| DataError::FieldNotFound(
-                        format!("Field '{}' not found", part)
-                    ))?;
+            };
+
+            if attempt >= self.retry.max_retries {
+                return Err(retryable);
             }
+            tokio::time::sleep(self.retry.delay(attempt)).await;
+            attempt += 1;
         }
-        
-        // Serialize the selected value to bytes
-        serde_json::to_vec(current)
-            .map_err(|e| DataError::SerializationError(e))
+    }
+
+    /// GET `source` and return the raw response body
+    async fn fetch_body(&self, source: &str) -> Result<Vec<u8>, DataError> {
+        let response = self.get_with_retry(source).await?;
+
+        let body = response.bytes()
+            .await
+            .map_err(|e| self.request_error("Failed to read body", e))?;
+        Ok(body.to_vec())
     }
 }
 
@@ -136,7 +433,6 @@ impl DataProvider for HttpProvider {
         // recorded fixtures instead of a real network call.
         if source == "https://example.com" {
             use std::path::PathBuf;
-            use super::zktls::{RecordedTlsProof, ZkTlsError};
 
             // 1. Load Fixtures (Simulating receiving proof + data)
             let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
@@ -152,7 +448,7 @@ impl DataProvider for HttpProvider {
 
             // Helper to read file or error
             let read_file = |p: &PathBuf| std::fs::read(p).map_err(|e| 
-                DataError::HttpError(format!("Failed to read fixture {}: {}", p.display(), e))
+                DataError::http_with_source(format!("Failed to read fixture {}", p.display()), e)
             );
 
             let metadata_bytes = read_file(&metadata_path)?;
@@ -161,7 +457,7 @@ impl DataProvider for HttpProvider {
 
             // 2. Parse metadata into Proof struct
             let proof: RecordedTlsProof = serde_json::from_slice(&metadata_bytes)
-                .map_err(|e| DataError::JsonError(format!("Invalid metadata JSON: {}", e)))?;
+                .map_err(DataError::JsonError)?;
 
             // 3. Verify the Proof
             // In the robust flow, we call verification with the loaded bytes.
@@ -170,44 +466,73 @@ impl DataProvider for HttpProvider {
                 &cert_bytes, 
                 &response_bytes, 
                 1735128100, // Use a time shortly after the fixture timestamp (simulating "now")
-                3600 // 1 hour max age
-            ).map_err(|e| DataError::HttpError(format!("Proof verification failed: {}", e)))?;
+                &VerifyPolicy::default() // 1 hour max age, 5 minute skew
+            ).map_err(|e| DataError::http_with_source("Proof verification failed", e))?;
 
              // 4. Return verified data
             let json: Value = serde_json::from_slice(&response_bytes)
-                .map_err(|e| DataError::JsonError(format!("Failed to parse response JSON: {}", e)))?;
+                .map_err(DataError::JsonError)?;
             
             return self.select_json_field(&json, query);
         }
 
         // Standard HTTP Fetch (for non-demo URLs)
-        let response = self.client
-            .get(source)
-            .send()
-            .await
-            .map_err(|e| DataError::HttpError(format!("Request failed: {}", e)))?;
+        let body = self.fetch_body(source).await?;
+        let json: Value = serde_json::from_slice(&body)
+            .map_err(DataError::JsonError)?;
         
-        if !response.status().is_success() {
-            return Err(DataError::HttpError(
-                format!("HTTP {} {}", response.status().as_u16(), response.status().canonical_reason().unwrap_or(""))
-            ));
+        self.select_json_field(&json, query)
+    }
+
+    /// Stream the body and stop as soon as a dotted `query` is located
+    ///
+    /// Values outside the path are skipped without being built, and the
+    /// connection is dropped once the field has been read. Queries with
+    /// array segments (or an empty query) fall back to `fetch`.
+    async fn fetch_streaming(&self, source: &str, query: &str) -> Result<Vec<u8>, DataError> {
+        if query.is_empty() || query.contains('[') {
+            return self.fetch(source, query).await;
         }
-        
-        let json: Value = response.json()
+
+        let mut response = self.get_with_retry(source).await?;
+
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+        let path = query.to_string();
+        let parser = tokio::task::spawn_blocking(move || {
+            let parts: Vec<&str> = path.split('.').collect();
+            find_json_path(ChunkReader::new(rx), &parts)
+        });
+
+        // Feed chunks until the body ends or the parser hangs up
+        loop {
+            tokio::select! {
+                _ = tx.closed() => break,
+                chunk = response.chunk() => {
+                    let chunk = chunk.map_err(|e| self.request_error("Failed to read body", e))?;
+                    match chunk {
+                        Some(bytes) if tx.send(bytes.to_vec()).await.is_ok() => {}
+                        _ => break,
+                    }
+                }
+            }
+        }
+        drop(tx);
+
+        let result = parser
             .await
-            .map_err(|e| DataError::JsonError(format!("Failed to parse JSON: {}", e)))?;
-        
-        self.select_json_field(&json, query)
+            .map_err(|e| DataError::http(format!("Streaming parser failed: {}", e)))?;
+        found_field(result, query)
     }
-    
+
     fn verify_tls_proof(&self, proof: &super::zktls::RecordedTlsProof) -> Result<(), super::zktls::ZkTlsError> {
         // 1. TRUST ANCHOR CHECK (Prevent Malicious Notary Attack)
         if proof.notary_pubkey != TRUSTED_NOTARY_PUBKEY {
             eprintln!("🚨 SECURITY ALERT: Proof signed by Untrusted Notary!");
             eprintln!("Expected: {}", TRUSTED_NOTARY_PUBKEY);
             eprintln!("Got:      {}", proof.notary_pubkey);
-            // We reuse SignatureInvalid for this meaningful security failure
-            return Err(super::zktls::ZkTlsError::SignatureInvalid("Untrusted Notary Public Key".into()));
+            return Err(super::zktls::ZkTlsError::UntrustedNotary {
+                notary_pubkey: proof.notary_pubkey.clone(),
+            });
         }
 
         // 2. CRYPTOGRAPHIC VERIFICATION is handled by the caller (proof.verify) in our current structure.
@@ -319,6 +644,348 @@ mod tests {
         assert!(matches!(result, Err(DataError::InvalidQuery(_))));
     }
     
+    const BODY: &str = r#"{"account":{"balance":5000}}"#;
+
+    /// Serve `BODY` on an ephemeral local port and return its URL
+    async fn serve_body() -> String {
+        use axum::{routing::get, Router};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route("/balance", get(|| async { BODY }));
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}/balance", addr)
+    }
+
+    /// Proof for `body` on the local test server, notarized by a test key
+    fn proof_for(body: &[u8]) -> RecordedTlsProof {
+        use sha2::{Digest, Sha256};
+
+        let mut proof = RecordedTlsProof {
+            domain: "127.0.0.1".to_string(),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            response_hash: hex::encode(Sha256::digest(body)),
+            cert_chain_hash: String::new(),
+            notary_pubkey: String::new(),
+            signature: String::new(),
+            cosignatures: Vec::new(),
+        };
+        proof.notarize(&ed25519_dalek::SigningKey::from_bytes(&[5u8; 32]));
+        proof
+    }
+
+    fn no_cert_policy() -> VerifyPolicy {
+        VerifyPolicy { require_cert_validation: false, ..VerifyPolicy::default() }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_verified_matching_proof() {
+        let url = serve_body().await;
+        let provider = HttpProvider::new();
+
+        let result = provider
+            .fetch_verified(&url, "account.balance", &proof_for(BODY.as_bytes()), b"", no_cert_policy())
+            .await
+            .unwrap();
+        assert_eq!(serde_json::from_slice::<u64>(&result).unwrap(), 5000);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_verified_mismatched_proof_aborts() {
+        let url = serve_body().await;
+        let provider = HttpProvider::new();
+
+        let stale = proof_for(br#"{"account":{"balance":999999}}"#);
+        let result = provider
+            .fetch_verified(&url, "account.balance", &stale, b"", no_cert_policy())
+            .await;
+        assert!(matches!(result, Err(DataError::TlsVerificationFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_verified_bad_signature_aborts() {
+        let url = serve_body().await;
+        let provider = HttpProvider::new();
+
+        // Body hash matches, but the signature doesn't come from the listed notary
+        let mut forged = proof_for(BODY.as_bytes());
+        forged.signature = hex::encode([7u8; 64]);
+        let result = provider
+            .fetch_verified(&url, "account.balance", &forged, b"", no_cert_policy())
+            .await;
+        assert!(matches!(result, Err(DataError::TlsVerificationFailed(ref msg)) if msg.contains("Signature")));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_surfaces_distinctly() {
+        use axum::{routing::get, Router};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_secs(3)).await;
+                BODY
+            }),
+        );
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let provider = HttpProvider::with_timeout(Duration::from_secs(1));
+        let result = provider.fetch(&format!("http://{}/slow", addr), "account.balance").await;
+        assert!(matches!(result, Err(DataError::Timeout { secs: 1 })));
+    }
+
+    /// Serve `BODY` on `/flaky` after failing the first `failures` requests
+    /// with `status`; returns the URL and the request counter
+    async fn serve_flaky(
+        failures: usize,
+        status: axum::http::StatusCode,
+    ) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        use axum::{routing::get, Router};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let counter = attempts.clone();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route(
+            "/flaky",
+            get(move || {
+                let counter = counter.clone();
+                async move {
+                    if counter.fetch_add(1, Ordering::SeqCst) < failures {
+                        Err(status)
+                    } else {
+                        Ok(BODY)
+                    }
+                }
+            }),
+        );
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (format!("http://{}/flaky", addr), attempts)
+    }
+
+    fn fast_retry() -> RetryConfig {
+        RetryConfig { max_retries: 3, base_delay: Duration::from_millis(10) }
+    }
+
+    #[tokio::test]
+    async fn test_retry_recovers_from_server_errors() {
+        use std::sync::atomic::Ordering;
+
+        let (url, attempts) = serve_flaky(2, axum::http::StatusCode::SERVICE_UNAVAILABLE).await;
+        let provider = HttpProvider::new().with_retry(fast_retry());
+
+        let result = provider.fetch(&url, "account.balance").await.unwrap();
+        assert_eq!(serde_json::from_slice::<u64>(&result).unwrap(), 5000);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_skips_client_errors() {
+        use std::sync::atomic::Ordering;
+
+        let (url, attempts) = serve_flaky(2, axum::http::StatusCode::NOT_FOUND).await;
+        let provider = HttpProvider::new().with_retry(fast_retry());
+
+        let result = provider.fetch(&url, "account.balance").await;
+        assert!(matches!(result, Err(DataError::HttpError { .. })));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // Without a retry policy a server error fails on the first attempt
+        let (url, attempts) = serve_flaky(1, axum::http::StatusCode::INTERNAL_SERVER_ERROR).await;
+        assert!(HttpProvider::new().fetch(&url, "account.balance").await.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    /// Serve `BODY` over TLS with a fresh self-signed certificate
+    ///
+    /// Returns the URL, the certificate's pin and a count of HTTP requests
+    /// that reached the server (i.e. completed a handshake).
+    async fn serve_tls_body() -> (String, String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let cert = rcgen::generate_simple_self_signed(vec!["127.0.0.1".to_string()]).unwrap();
+        let cert_der = cert.serialize_der().unwrap();
+        let pin = super::super::pinning::cert_sha256(&cert_der);
+        let config = rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(
+                vec![rustls::Certificate(cert_der)],
+                rustls::PrivateKey(cert.serialize_private_key_der()),
+            )
+            .unwrap();
+        let acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(config));
+
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let Ok(mut tls) = acceptor.accept(tcp).await else { continue };
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match tls.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    BODY.len(),
+                    BODY
+                );
+                let _ = tls.write_all(response.as_bytes()).await;
+                let _ = tls.shutdown().await;
+            }
+        });
+        (format!("https://127.0.0.1:{}/balance", addr.port()), pin, requests)
+    }
+
+    #[tokio::test]
+    async fn test_pinned_cert_over_tls() {
+        use std::sync::atomic::Ordering;
+
+        let (url, pin, requests) = serve_tls_body().await;
+
+        // Right pin: the self-signed certificate is accepted
+        let provider = HttpProvider::new().with_pinned_cert(pin.to_ascii_uppercase());
+        let result = provider.fetch(&url, "account.balance").await.unwrap();
+        assert_eq!(serde_json::from_slice::<u64>(&result).unwrap(), 5000);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Wrong pin: the handshake fails before the request is sent, and
+        // the failure isn't retried
+        let provider = HttpProvider::new()
+            .with_pinned_cert("ab".repeat(32))
+            .with_retry(fast_retry());
+        match provider.fetch(&url, "account.balance").await {
+            Err(DataError::CertPinMismatch { expected, got }) => {
+                assert_eq!(expected, "ab".repeat(32));
+                assert_eq!(got, pin);
+            }
+            other => panic!("expected CertPinMismatch, got {:?}", other),
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_verified_requires_pinned_cert_hash() {
+        let (url, pin, _) = serve_tls_body().await;
+        let provider = HttpProvider::new().with_pinned_cert(pin.clone());
+
+        let mut proof = proof_for(BODY.as_bytes());
+        proof.cert_chain_hash = pin.clone();
+        proof.notarize(&ed25519_dalek::SigningKey::from_bytes(&[5u8; 32]));
+        let result = provider
+            .fetch_verified(&url, "account.balance", &proof, b"", no_cert_policy())
+            .await
+            .unwrap();
+        assert_eq!(serde_json::from_slice::<u64>(&result).unwrap(), 5000);
+
+        // A proof recorded against some other certificate is refused
+        let other = proof_for(BODY.as_bytes());
+        let result = provider
+            .fetch_verified(&url, "account.balance", &other, b"", no_cert_policy())
+            .await;
+        assert!(matches!(result, Err(DataError::CertPinMismatch { ref expected, .. }) if *expected == pin));
+    }
+
+    #[tokio::test]
+    async fn test_pinned_cert_rejects_unpinned_server() {
+        let url = serve_body().await;
+
+        // Plain HTTP presents no certificate, so no pin can match it
+        let provider = HttpProvider::new().with_pinned_cert("AB".repeat(32));
+        let result = provider.fetch(&url, "account.balance").await;
+        match result {
+            Err(DataError::CertPinMismatch { expected, got }) => {
+                assert_eq!(expected, "ab".repeat(32));
+                assert_eq!(got, "no certificate");
+            }
+            other => panic!("expected CertPinMismatch, got {:?}", other),
+        }
+
+        assert!(HttpProvider::new().fetch(&url, "account.balance").await.is_ok());
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off() {
+        let retry = RetryConfig { max_retries: 3, base_delay: Duration::from_millis(100) };
+        for attempt in 0..3 {
+            let full = Duration::from_millis(100) * 2u32.pow(attempt);
+            let delay = retry.delay(attempt);
+            assert!(delay >= full / 2 && delay <= full, "attempt {}: {:?}", attempt, delay);
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_select_json_field_never_panics(path in "[a-z\\[\\]0-9.]{0,24}") {
+            static PROVIDER: std::sync::OnceLock<HttpProvider> = std::sync::OnceLock::new();
+            let provider = PROVIDER.get_or_init(HttpProvider::new);
+            let json = json!({"a": {"b": [1, {"c": 2}]}, "items": [1, 2, 3]});
+            let _ = provider.select_json_field(&json, &path);
+        }
+    }
+
+    #[test]
+    fn test_select_negative_index() {
+        let provider = HttpProvider::new();
+        let json = json!({"items": [1, 2, 3]});
+        
+        assert_eq!(provider.select_json_field(&json, "items[-1]").unwrap(), b"3");
+        assert_eq!(provider.select_json_field(&json, "items[-3]").unwrap(), b"1");
+    }
+    
+    #[test]
+    fn test_negative_index_out_of_range() {
+        let provider = HttpProvider::new();
+        let json = json!({"items": [1, 2, 3]});
+        
+        for path in ["items[-4]", "items[-0]"] {
+            let result = provider.select_json_field(&json, path);
+            assert!(matches!(result, Err(DataError::FieldNotFound(_))), "{}", path);
+        }
+        let result = provider.select_json_field(&json, "items[--1]");
+        assert!(matches!(result, Err(DataError::InvalidQuery(_))));
+    }
+    
+    #[test]
+    fn test_select_wildcard() {
+        let provider = HttpProvider::new();
+        let json = json!({
+            "data": {
+                "users": [
+                    {"name": "Alice", "age": 30},
+                    {"name": "Bob", "age": 25}
+                ]
+            }
+        });
+        
+        let result = provider.select_json_field(&json, "data.users[*].age").unwrap();
+        assert_eq!(result, b"[30,25]");
+        
+        // A missing sub-field in any element fails the whole selection
+        let result = provider.select_json_field(&json, "data.users[*].email");
+        assert!(matches!(result, Err(DataError::FieldNotFound(_))));
+        
+        let result = provider.select_json_field(&json, "data[*]");
+        assert!(matches!(result, Err(DataError::FieldNotFound(_))));
+    }
+    
     #[test]
     fn test_array_out_of_bounds() {
         let provider = HttpProvider::new();
@@ -327,4 +994,40 @@ mod tests {
         let result = provider.select_json_field(&json, "items[10]");
         assert!(matches!(result, Err(DataError::FieldNotFound(_))));
     }
+
+    /// Serve a large synthetic document on `/large` and the same document
+    /// cut off mid-array on `/truncated`
+    async fn serve_large_body() -> String {
+        use axum::{routing::get, Router};
+
+        let history: Vec<String> = (0..200_000).map(|i| format!(r#"{{"tx":{},"amount":{}}}"#, i, i * 3)).collect();
+        let body = format!(r#"{{"meta":{{"page":1}},"data":{{"balance":4200,"history":[{}]}}}}"#, history.join(","));
+        let truncated = body[..body.len() / 2].to_string();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new()
+            .route("/large", get(move || async move { body }))
+            .route("/truncated", get(move || async move { truncated }));
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_streaming_large_body() {
+        let base = serve_large_body().await;
+        let provider = HttpProvider::new();
+
+        let streamed = provider.fetch_streaming(&format!("{}/large", base), "data.balance").await.unwrap();
+        assert_eq!(streamed, b"4200");
+        assert_eq!(provider.fetch(&format!("{}/large", base), "data.balance").await.unwrap(), streamed);
+
+        // The field is found before the broken tail, which `fetch` can't parse
+        let truncated = format!("{}/truncated", base);
+        assert_eq!(provider.fetch_streaming(&truncated, "data.balance").await.unwrap(), b"4200");
+        assert!(matches!(provider.fetch(&truncated, "data.balance").await, Err(DataError::JsonError(_))));
+
+        let missing = provider.fetch_streaming(&format!("{}/large", base), "data.limit").await;
+        assert!(matches!(missing, Err(DataError::FieldNotFound(_))));
+    }
 }
@@ -9,6 +9,7 @@
 //! The module follows the **Ports and Adapters** pattern:
 //! - `DataProvider` trait is the **Port** (interface)
 //! - `HttpProvider` is an **Adapter** (implementation)
+//! - `DecoProvider` is an **Adapter** backed by DECO-style three-party sessions
 //!
 //! ## Future: zkTLS Integration
 //!
@@ -53,9 +54,13 @@ mod provider;
 mod http;
 mod builder;
 mod zktls;
+mod deco;
+mod pinning;
+mod streaming;
 
-pub use error::DataError;
+pub use error::{BuilderError, DataError};
 pub use provider::DataProvider;
-pub use http::HttpProvider;
+pub use http::{HttpProvider, RetryConfig};
 pub use builder::ZkInputBuilder;
-pub use zktls::{RecordedTlsProof, ZkTlsError};
+pub use zktls::{RecordedTlsProof, VerifyPolicy, ZkTlsError};
+pub use deco::{combine_shares, record_mac, DecoProvider, DecoRecord, DecoSession, DecoTransport, VerifierReveal};
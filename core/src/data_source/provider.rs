@@ -53,9 +53,21 @@ pub trait DataProvider: Send + Sync {
     /// - `"balance"` - Top-level field
     /// - `"account.balance"` - Nested field
     /// - `"items[0]"` - Array indexing
+    /// - `"items[-1]"` - Last element
+    /// - `"users[*].age"` - Every element's field, as a JSON array
     /// - `"data.users[2].name"` - Complex path
     async fn fetch(&self, source: &str, query: &str) -> Result<Vec<u8>, DataError>;
-    
+
+    /// Fetch a field without buffering the whole response
+    ///
+    /// Intended for large responses: implementations that can stream parse
+    /// the body as it arrives and stop reading once `query` is found. The
+    /// result is the same as `fetch`. The default implementation just calls
+    /// `fetch`.
+    async fn fetch_streaming(&self, source: &str, query: &str) -> Result<Vec<u8>, DataError> {
+        self.fetch(source, query).await
+    }
+
     /// Verify a recorded TLS proof
     ///
     /// Validates that the provided proof matches the expected domain, certificate chain,
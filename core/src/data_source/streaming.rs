@@ -0,0 +1,179 @@
+//! Incremental JSON field lookup for large response bodies
+//!
+//! `find_json_path` walks a JSON document straight off a reader, skipping
+//! every value outside the requested dotted path without building it, and
+//! stops as soon as the target value has been read. Bytes after the target
+//! are never pulled from the reader, so a chunked HTTP body can be abandoned
+//! part-way through.
+
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::fmt;
+use std::io::Read;
+use tokio::sync::mpsc;
+use super::error::DataError;
+
+/// Sentinel error used to unwind the parser once the target value is stored
+const FOUND: &str = "streaming lookup: target found";
+
+/// Look up the dotted object path `parts` in the JSON document on `reader`
+///
+/// Only object keys are supported (no array segments). Returns `Ok(None)`
+/// if the document was read completely and the path doesn't exist.
+///
+/// # Errors
+///
+/// Returns the parser error if the document is malformed before the target
+/// is reached. Anything after the target is not inspected.
+pub(crate) fn find_json_path<R: Read>(reader: R, parts: &[&str]) -> Result<Option<Value>, serde_json::Error> {
+    let found = RefCell::new(None);
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let result = PathSeed { parts, found: &found }.deserialize(&mut deserializer);
+
+    if let Some(value) = found.into_inner() {
+        return Ok(Some(value));
+    }
+    result?;
+    deserializer.end()?;
+    Ok(None)
+}
+
+/// Descends one path segment per nesting level
+struct PathSeed<'a> {
+    parts: &'a [&'a str],
+    found: &'a RefCell<Option<Value>>,
+}
+
+impl<'de> DeserializeSeed<'de> for PathSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        if self.parts.is_empty() {
+            let value = <Value as serde::Deserialize>::deserialize(deserializer)?;
+            *self.found.borrow_mut() = Some(value);
+            return Err(de::Error::custom(FOUND));
+        }
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for PathSeed<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any JSON value")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == self.parts[0] {
+                map.next_value_seed(PathSeed { parts: &self.parts[1..], found: self.found })?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+
+    // Anything other than an object can't contain the rest of the path
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+/// Blocking `Read` over body chunks sent from an async task
+///
+/// Reports end of input once the sending side is dropped.
+pub(crate) struct ChunkReader {
+    chunks: mpsc::Receiver<Vec<u8>>,
+    current: Vec<u8>,
+    pos: usize,
+}
+
+impl ChunkReader {
+    pub(crate) fn new(chunks: mpsc::Receiver<Vec<u8>>) -> Self {
+        Self { chunks, current: Vec::new(), pos: 0 }
+    }
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.pos == self.current.len() {
+            match self.chunks.blocking_recv() {
+                Some(chunk) => {
+                    self.current = chunk;
+                    self.pos = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+        let n = buf.len().min(self.current.len() - self.pos);
+        buf[..n].copy_from_slice(&self.current[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// Map a lookup result for `query` to the provider's field bytes
+pub(crate) fn found_field(result: Result<Option<Value>, serde_json::Error>, query: &str) -> Result<Vec<u8>, DataError> {
+    match result.map_err(DataError::JsonError)? {
+        Some(value) => serde_json::to_vec(&value).map_err(DataError::SerializationError),
+        None => Err(DataError::FieldNotFound(format!("Field '{}' not found", query))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(body: &str, path: &str) -> Result<Option<Value>, serde_json::Error> {
+        let parts: Vec<&str> = path.split('.').collect();
+        find_json_path(body.as_bytes(), &parts)
+    }
+
+    #[test]
+    fn test_finds_nested_field_and_skips_siblings() {
+        let body = r#"{"meta":[1,{"balance":7}],"data":{"name":"x","balance":{"amount":42}}}"#;
+        assert_eq!(lookup(body, "data.balance").unwrap(), Some(serde_json::json!({"amount": 42})));
+    }
+
+    #[test]
+    fn test_stops_after_target() {
+        // The trailing garbage is never read
+        let body = r#"{"data":{"balance":42,"history":[1,2,"#;
+        assert_eq!(lookup(body, "data.balance").unwrap(), Some(serde_json::json!(42)));
+    }
+
+    #[test]
+    fn test_missing_path() {
+        assert_eq!(lookup(r#"{"data":{"other":1}}"#, "data.balance").unwrap(), None);
+        assert_eq!(lookup(r#"{"data":5}"#, "data.balance").unwrap(), None);
+        assert!(lookup(r#"{"data":{"other":1"#, "data.balance").is_err());
+    }
+}
@@ -1,8 +1,7 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use sha2::{Digest, Sha256};
-// TODO: Update to use ECDSA signatures for EVM compatibility
-// use ed25519_dalek::{VerifyingKey, Signature, Verifier}; // robust API
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
 
 
 #[derive(Debug, Error)]
@@ -21,6 +20,44 @@ pub enum ZkTlsError {
 
     #[error("Notary Signature Invalid: {0}")]
     SignatureInvalid(String),
+
+    #[error("Untrusted notary: {notary_pubkey} is not in the trusted set")]
+    UntrustedNotary { notary_pubkey: String },
+
+    #[error("Notary threshold not met: {valid} of {required} required trusted signatures")]
+    ThresholdNotMet { valid: usize, required: usize },
+}
+
+/// Default maximum proof age (1 hour)
+pub const DEFAULT_MAX_AGE_SECS: u64 = 3600;
+
+/// Default tolerance for proof timestamps ahead of the verifier's clock (5 minutes)
+pub const DEFAULT_MAX_FUTURE_SKEW_SECS: u64 = 300;
+
+/// Tunable checks applied by `RecordedTlsProof::verify`.
+///
+/// Notaries and chains differ in how tightly their clocks are synchronized,
+/// so both the freshness window and the future-skew tolerance are configurable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifyPolicy {
+    /// Maximum age of a proof, in seconds, before it is treated as a replay
+    pub max_age: u64,
+
+    /// Maximum number of seconds a proof timestamp may be ahead of `current_time`
+    pub max_future_skew: u64,
+
+    /// Whether the certificate chain hash must match the provided chain
+    pub require_cert_validation: bool,
+}
+
+impl Default for VerifyPolicy {
+    fn default() -> Self {
+        Self {
+            max_age: DEFAULT_MAX_AGE_SECS,
+            max_future_skew: DEFAULT_MAX_FUTURE_SKEW_SECS,
+            require_cert_validation: true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +73,11 @@ pub struct RecordedTlsProof {
     /// The Ed25519 Signature of the Metadata (Hex)
     /// Signs: sha256(domain + ":" + timestamp + ":" + response_hash + ":" + cert_chain_hash)
     pub signature: String,
+
+    /// Additional `(notary_pubkey, signature)` pairs over the same message,
+    /// for M-of-N notary setups (see `verify_threshold`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cosignatures: Vec<(String, String)>,
 }
 
 impl RecordedTlsProof {
@@ -45,7 +87,27 @@ impl RecordedTlsProof {
         cert_chain_pem: &[u8],
         response_body: &[u8],
         current_time: u64,
-        max_age_secs: u64,
+        policy: &VerifyPolicy,
+    ) -> Result<(), ZkTlsError> {
+        self.check_session(expected_domain, cert_chain_pem, response_body, current_time, policy)?;
+
+        // 3. Signature (authenticity)
+        // This ensures the metadata was produced by holder of notary_pubkey.
+        // NOTE: This does not check that notary_pubkey is trusted; use
+        // `verify_with_allowlist` for that.
+        self.verify_signature()?;
+
+        Ok(())
+    }
+
+    /// Domain, freshness and hash checks shared by every verification path
+    fn check_session(
+        &self,
+        expected_domain: &str,
+        cert_chain_pem: &[u8],
+        response_body: &[u8],
+        current_time: u64,
+        policy: &VerifyPolicy,
     ) -> Result<(), ZkTlsError> {
         // 0. Basic domain match
         if self.domain != expected_domain {
@@ -56,23 +118,24 @@ impl RecordedTlsProof {
         }
 
         // 1. Timestamp checks: not too old
-        if current_time > self.timestamp + max_age_secs {
+        if current_time > self.timestamp.saturating_add(policy.max_age) {
             return Err(ZkTlsError::ReplayDetected {
                 timestamp: self.timestamp,
-                max_age: max_age_secs,
+                max_age: policy.max_age,
             });
         }
 
-        // 1b. Optional: reject timestamps too far in the future (small clock skew)
-        const MAX_FUTURE_SKEW_SECS: u64 = 300;
-        if self.timestamp > current_time + MAX_FUTURE_SKEW_SECS {
+        // 1b. Reject timestamps too far in the future (clock skew tolerance)
+        if self.timestamp > current_time.saturating_add(policy.max_future_skew) {
             return Err(ZkTlsError::SignatureInvalid("Timestamp is in the future".into()));
         }
 
         // 2. Integrity checks (hashes)
-        let computed_cert_hash = sha256_hex(cert_chain_pem);
-        if computed_cert_hash != self.cert_chain_hash {
-            return Err(ZkTlsError::CertChainMismatch);
+        if policy.require_cert_validation {
+            let computed_cert_hash = sha256_hex(cert_chain_pem);
+            if computed_cert_hash != self.cert_chain_hash {
+                return Err(ZkTlsError::CertChainMismatch);
+            }
         }
 
         let computed_response_hash = sha256_hex(response_body);
@@ -80,77 +143,135 @@ impl RecordedTlsProof {
             return Err(ZkTlsError::ResponseTampered);
         }
 
-        // 3. Signature (authenticity)
-        // This ensures the metadata was produced by holder of notary_pubkey.
-        // NOTE: You MUST ensure notary_pubkey is trusted (allowlist / PKI). See TODO below.
-        self.verify_signature()?;
-
         Ok(())
     }
 
-    pub fn verify_signature(&self) -> Result<(), ZkTlsError> {
-        // Canonical message: "domain:timestamp:response_hash:cert_chain_hash"
-        let message = format!(
-            "{}:{}:{}:{}",
-            self.domain, self.timestamp, self.response_hash, self.cert_chain_hash
-        );
-        let message_bytes = message.as_bytes();
-
-        // Decode pubkey hex
-        let pub_key_bytes = hex::decode(&self.notary_pubkey)
-            .map_err(|_| ZkTlsError::SignatureInvalid("Bad hex in notary_pubkey".into()))?;
-        if pub_key_bytes.len() != 32 {
-            return Err(ZkTlsError::SignatureInvalid(format!(
-                "Invalid public key length: {} bytes",
-                pub_key_bytes.len()
-            )));
+    /// `verify`, then require `notary_pubkey` to be one of `trusted_notaries`
+    ///
+    /// A valid signature only proves the metadata came from *some* key holder;
+    /// without this check a self-generated notary key would pass. Keys are
+    /// compared as hex, case-insensitively.
+    pub fn verify_with_allowlist(
+        &self,
+        expected_domain: &str,
+        cert_chain_pem: &[u8],
+        response_body: &[u8],
+        current_time: u64,
+        policy: &VerifyPolicy,
+        trusted_notaries: &[String],
+    ) -> Result<(), ZkTlsError> {
+        self.verify(expected_domain, cert_chain_pem, response_body, current_time, policy)?;
+
+        if !trusted_notaries
+            .iter()
+            .any(|trusted| trusted.eq_ignore_ascii_case(&self.notary_pubkey))
+        {
+            return Err(ZkTlsError::UntrustedNotary {
+                notary_pubkey: self.notary_pubkey.clone(),
+            });
         }
 
-        // Decode signature hex
-        let sig_bytes = hex::decode(&self.signature)
-            .map_err(|_| ZkTlsError::SignatureInvalid("Bad hex in signature".into()))?;
-        if sig_bytes.len() != 64 {
-            return Err(ZkTlsError::SignatureInvalid(format!(
-                "Invalid signature length: {} bytes",
-                sig_bytes.len()
-            )));
+        Ok(())
+    }
+
+    /// Session checks, then require at least `required` distinct trusted
+    /// notaries to have signed the canonical message
+    ///
+    /// Signers are the primary `notary_pubkey`/`signature` plus every entry
+    /// in `cosignatures`. Untrusted keys, invalid signatures and repeats of
+    /// the same key don't count towards the threshold.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_threshold(
+        &self,
+        expected_domain: &str,
+        cert_chain_pem: &[u8],
+        response_body: &[u8],
+        current_time: u64,
+        policy: &VerifyPolicy,
+        required: usize,
+        trusted_notaries: &[String],
+    ) -> Result<(), ZkTlsError> {
+        self.check_session(expected_domain, cert_chain_pem, response_body, current_time, policy)?;
+
+        let message = self.canonical_message();
+        let signers = std::iter::once((&self.notary_pubkey, &self.signature))
+            .chain(self.cosignatures.iter().map(|(pubkey, sig)| (pubkey, sig)));
+
+        let mut counted: Vec<String> = Vec::new();
+        for (pubkey, signature) in signers {
+            let pubkey = pubkey.to_ascii_lowercase();
+            if counted.contains(&pubkey)
+                || !trusted_notaries.iter().any(|t| t.eq_ignore_ascii_case(&pubkey))
+                || verify_notary_signature(&message, &pubkey, signature).is_err()
+            {
+                continue;
+            }
+            counted.push(pubkey);
         }
 
-        // Convert to fixed-size arrays then into types
-        let pub_key_arr: [u8; 32] = pub_key_bytes
-            .as_slice()
-            .try_into()
-            .map_err(|_| ZkTlsError::SignatureInvalid("Public key conversion failed".into()))?;
-        let sig_arr: [u8; 64] = sig_bytes
-            .as_slice()
-            .try_into()
-            .map_err(|_| ZkTlsError::SignatureInvalid("Signature conversion failed".into()))?;
-
-        // TODO: Update to use ECDSA signature verification for EVM compatibility
-        // Currently commented out as ed25519-dalek has been removed
-        /*
-        // ed25519-dalek 2.0 API: Use VerifyingKey instead of PublicKey
-        let mut pub_key_arr = [0u8; 32];
-        pub_key_arr.copy_from_slice(&self.notary_pubkey);
-        
-        let verifying_key = VerifyingKey::from_bytes(&pub_key_arr)
-            .map_err(|e| ZkTlsError::SignatureInvalid(e.to_string()))?;
-        
-        // ed25519-dalek 2.0 API: Signature::from([u8; 64])
-        let mut sig_arr = [0u8; 64];
-        sig_arr.copy_from_slice(&self.signature);
-        let signature = Signature::from(sig_arr);
-        
-        // Verify the signature
-        verifying_key
-            .verify(&message, &signature)
-            .map_err(|e| ZkTlsError::SignatureInvalid(e.to_string()))?;
-        */
-        
-        // For now, skip signature verification (will be implemented with ECDSA)
-        // In production, this MUST be implemented for security
+        if counted.len() < required {
+            return Err(ZkTlsError::ThresholdNotMet { valid: counted.len(), required });
+        }
         Ok(())
     }
+
+    /// Set `notary` as the primary signer and sign the canonical message
+    pub fn notarize(&mut self, notary: &SigningKey) {
+        self.notary_pubkey = hex::encode(notary.verifying_key().as_bytes());
+        self.signature = self.sign_with(notary);
+    }
+
+    /// Append `notary`'s signature over the canonical message to `cosignatures`
+    pub fn cosign(&mut self, notary: &SigningKey) {
+        let pubkey = hex::encode(notary.verifying_key().as_bytes());
+        let signature = self.sign_with(notary);
+        self.cosignatures.push((pubkey, signature));
+    }
+
+    fn sign_with(&self, notary: &SigningKey) -> String {
+        let digest = Sha256::digest(self.canonical_message().as_bytes());
+        hex::encode(notary.sign(&digest).to_bytes())
+    }
+
+    /// Check the primary notary's Ed25519 signature over the canonical message
+    pub fn verify_signature(&self) -> Result<(), ZkTlsError> {
+        verify_notary_signature(&self.canonical_message(), &self.notary_pubkey, &self.signature)
+    }
+
+    /// Canonical message: "domain:timestamp:response_hash:cert_chain_hash"
+    fn canonical_message(&self) -> String {
+        format!(
+            "{}:{}:{}:{}",
+            self.domain, self.timestamp, self.response_hash, self.cert_chain_hash
+        )
+    }
+}
+
+/// Check one notary's hex Ed25519 `signature` over `sha256(message)`
+fn verify_notary_signature(message: &str, notary_pubkey: &str, signature: &str) -> Result<(), ZkTlsError> {
+    // Decode pubkey hex
+    let pub_key_arr: [u8; 32] = hex::decode(notary_pubkey)
+        .map_err(|_| ZkTlsError::SignatureInvalid("Bad hex in notary_pubkey".into()))?
+        .try_into()
+        .map_err(|bytes: Vec<u8>| {
+            ZkTlsError::SignatureInvalid(format!("Invalid public key length: {} bytes", bytes.len()))
+        })?;
+
+    // Decode signature hex
+    let sig_arr: [u8; 64] = hex::decode(signature)
+        .map_err(|_| ZkTlsError::SignatureInvalid("Bad hex in signature".into()))?
+        .try_into()
+        .map_err(|bytes: Vec<u8>| {
+            ZkTlsError::SignatureInvalid(format!("Invalid signature length: {} bytes", bytes.len()))
+        })?;
+
+    let verifying_key = VerifyingKey::from_bytes(&pub_key_arr)
+        .map_err(|e| ZkTlsError::SignatureInvalid(e.to_string()))?;
+
+    // `verify_strict` also rejects small-order keys and malleable signatures
+    verifying_key
+        .verify_strict(&Sha256::digest(message.as_bytes()), &Signature::from_bytes(&sig_arr))
+        .map_err(|e| ZkTlsError::SignatureInvalid(e.to_string()))
 }
 
 fn sha256_hex(data: &[u8]) -> String {
@@ -158,3 +279,206 @@ fn sha256_hex(data: &[u8]) -> String {
     hasher.update(data);
     hex::encode(hasher.finalize())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERT: &[u8] = b"-----BEGIN CERTIFICATE-----test-----END CERTIFICATE-----";
+    const BODY: &[u8] = br#"{"balance": 1000}"#;
+    const NOW: u64 = 1_735_128_100;
+
+    /// Deterministic test notary `n`
+    fn notary(n: u8) -> SigningKey {
+        SigningKey::from_bytes(&[n; 32])
+    }
+
+    fn notary_hex(n: u8) -> String {
+        hex::encode(notary(n).verifying_key().as_bytes())
+    }
+
+    /// Proof at `timestamp` notarized by `notary(1)`
+    fn proof_at(timestamp: u64) -> RecordedTlsProof {
+        let mut proof = RecordedTlsProof {
+            domain: "example.com".to_string(),
+            timestamp,
+            response_hash: sha256_hex(BODY),
+            cert_chain_hash: sha256_hex(CERT),
+            notary_pubkey: String::new(),
+            signature: String::new(),
+            cosignatures: Vec::new(),
+        };
+        proof.notarize(&notary(1));
+        proof
+    }
+
+    #[test]
+    fn test_signature_verified() {
+        let proof = proof_at(NOW);
+        assert!(proof.verify_signature().is_ok());
+
+        // Any change to the signed metadata invalidates the signature
+        let mut altered = proof.clone();
+        altered.timestamp += 1;
+        assert!(matches!(altered.verify_signature(), Err(ZkTlsError::SignatureInvalid(_))));
+
+        let mut forged = proof;
+        forged.signature = hex::encode([2u8; 64]);
+        assert!(matches!(forged.verify_signature(), Err(ZkTlsError::SignatureInvalid(_))));
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let policy = VerifyPolicy::default();
+        assert_eq!(policy.max_age, 3600);
+        assert_eq!(policy.max_future_skew, 300);
+        assert!(policy.require_cert_validation);
+
+        assert!(proof_at(NOW).verify("example.com", CERT, BODY, NOW, &policy).is_ok());
+    }
+
+    #[test]
+    fn test_custom_future_skew_window() {
+        let policy = VerifyPolicy { max_future_skew: 10, ..Default::default() };
+
+        // Just inside the window
+        assert!(proof_at(NOW + 10).verify("example.com", CERT, BODY, NOW, &policy).is_ok());
+
+        // Just outside the window
+        let result = proof_at(NOW + 11).verify("example.com", CERT, BODY, NOW, &policy);
+        assert!(matches!(result, Err(ZkTlsError::SignatureInvalid(_))));
+    }
+
+    #[test]
+    fn test_custom_max_age() {
+        let policy = VerifyPolicy { max_age: 60, ..Default::default() };
+
+        assert!(proof_at(NOW - 60).verify("example.com", CERT, BODY, NOW, &policy).is_ok());
+
+        let result = proof_at(NOW - 61).verify("example.com", CERT, BODY, NOW, &policy);
+        assert!(matches!(result, Err(ZkTlsError::ReplayDetected { max_age: 60, .. })));
+    }
+
+    #[test]
+    fn test_cert_validation_can_be_disabled() {
+        let strict = VerifyPolicy::default();
+        let lenient = VerifyPolicy { require_cert_validation: false, ..Default::default() };
+
+        let result = proof_at(NOW).verify("example.com", b"other cert", BODY, NOW, &strict);
+        assert!(matches!(result, Err(ZkTlsError::CertChainMismatch)));
+        assert!(proof_at(NOW).verify("example.com", b"other cert", BODY, NOW, &lenient).is_ok());
+    }
+
+    #[test]
+    fn test_allowlisted_notary_passes() {
+        let proof = proof_at(NOW);
+        let trusted = vec![notary_hex(9), notary_hex(1).to_ascii_uppercase()];
+
+        let result = proof.verify_with_allowlist("example.com", CERT, BODY, NOW, &VerifyPolicy::default(), &trusted);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_untrusted_notary_rejected() {
+        let policy = VerifyPolicy::default();
+        let mut proof = proof_at(NOW);
+        proof.notarize(&notary(8));
+        let trusted = vec![notary_hex(1)];
+
+        // Genuinely signed, so signature and integrity checks pass on their own
+        assert!(proof.verify("example.com", CERT, BODY, NOW, &policy).is_ok());
+
+        let result = proof.verify_with_allowlist("example.com", CERT, BODY, NOW, &policy, &trusted);
+        assert!(matches!(result, Err(ZkTlsError::UntrustedNotary { ref notary_pubkey }) if *notary_pubkey == notary_hex(8)));
+        assert!(matches!(
+            proof.verify_with_allowlist("example.com", CERT, BODY, NOW, &policy, &[]),
+            Err(ZkTlsError::UntrustedNotary { .. })
+        ));
+    }
+
+    #[test]
+    fn test_trusted_pubkey_with_forged_signature_rejected() {
+        let policy = VerifyPolicy::default();
+        let trusted = vec![notary_hex(1)];
+
+        // Attacker copies the trusted key and signs with their own
+        let mut forged = proof_at(NOW);
+        let attacker_signed = {
+            let mut p = forged.clone();
+            p.notarize(&notary(8));
+            p.signature
+        };
+        forged.signature = attacker_signed;
+        let result = forged.verify_with_allowlist("example.com", CERT, BODY, NOW, &policy, &trusted);
+        assert!(matches!(result, Err(ZkTlsError::SignatureInvalid(_))));
+
+        // Or attaches arbitrary bytes
+        forged.signature = hex::encode([2u8; 64]);
+        let result = forged.verify_with_allowlist("example.com", CERT, BODY, NOW, &policy, &trusted);
+        assert!(matches!(result, Err(ZkTlsError::SignatureInvalid(_))));
+    }
+
+    /// Proof signed by the notaries `1..=signers`
+    fn proof_signed_by(signers: u8) -> RecordedTlsProof {
+        let mut proof = proof_at(NOW);
+        for n in 2..=signers {
+            proof.cosign(&notary(n));
+        }
+        proof
+    }
+
+    fn three_notaries() -> Vec<String> {
+        (1..=3).map(notary_hex).collect()
+    }
+
+    #[test]
+    fn test_threshold_two_of_three() {
+        let policy = VerifyPolicy::default();
+        let proof = proof_signed_by(2);
+
+        assert!(proof.verify_threshold("example.com", CERT, BODY, NOW, &policy, 2, &three_notaries()).is_ok());
+        // The single-signature path still sees only the primary notary
+        assert!(proof.verify("example.com", CERT, BODY, NOW, &policy).is_ok());
+    }
+
+    #[test]
+    fn test_threshold_one_of_three_fails() {
+        let policy = VerifyPolicy::default();
+        let result = proof_signed_by(1).verify_threshold("example.com", CERT, BODY, NOW, &policy, 2, &three_notaries());
+        assert!(matches!(result, Err(ZkTlsError::ThresholdNotMet { valid: 1, required: 2 })));
+    }
+
+    #[test]
+    fn test_threshold_rejects_forged_cosignature() {
+        let policy = VerifyPolicy::default();
+        let mut proof = proof_at(NOW);
+
+        // Trusted pubkey listed with a well-formed signature it never produced
+        proof.cosignatures.push((notary_hex(2), hex::encode([2u8; 64])));
+        let result = proof.verify_threshold("example.com", CERT, BODY, NOW, &policy, 2, &three_notaries());
+        assert!(matches!(result, Err(ZkTlsError::ThresholdNotMet { valid: 1, .. })));
+
+        // A real signature from notary 3 over different metadata doesn't count either
+        let mut other = proof_at(NOW - 1);
+        other.cosign(&notary(3));
+        proof.cosignatures.push(other.cosignatures.remove(0));
+        let result = proof.verify_threshold("example.com", CERT, BODY, NOW, &policy, 2, &three_notaries());
+        assert!(matches!(result, Err(ZkTlsError::ThresholdNotMet { valid: 1, .. })));
+    }
+
+    #[test]
+    fn test_threshold_ignores_repeats_and_invalid_signers() {
+        let policy = VerifyPolicy::default();
+        let mut proof = proof_signed_by(1);
+        // Same key as the primary signer, uppercased
+        proof.cosign(&notary(1));
+        proof.cosignatures[0].0 = proof.cosignatures[0].0.to_ascii_uppercase();
+        // Trusted key with a malformed signature
+        proof.cosignatures.push((notary_hex(2), "00".to_string()));
+        // Valid signature from a key outside the trusted set
+        proof.cosign(&notary(7));
+
+        let result = proof.verify_threshold("example.com", CERT, BODY, NOW, &policy, 2, &three_notaries());
+        assert!(matches!(result, Err(ZkTlsError::ThresholdNotMet { valid: 1, .. })));
+    }
+}
@@ -0,0 +1,30 @@
+//! # Fuzzing Entry Points
+//!
+//! Thin wrappers over the parsers that handle attacker-controlled bytes, built
+//! only under `cfg(fuzzing)` (set by `cargo fuzz`). Each must return without
+//! panicking for every input.
+
+use crate::data_source::HttpProvider;
+use crate::rwa::{RwaClaim, RwaPublicValues};
+
+/// Fuzz `RwaPublicValues::from_wire`
+pub fn public_values_from_wire(data: &[u8]) {
+    let _ = RwaPublicValues::from_wire(data);
+}
+
+/// Fuzz the unversioned and versioned `RwaClaim` decoders
+pub fn claim_from_bytes(data: &[u8]) {
+    let _ = RwaClaim::try_from_bytes(data);
+    let _ = RwaClaim::from_input_bytes(data);
+}
+
+/// Fuzz the JSON path selector: the first line is the path, the rest is JSON
+pub fn json_selector(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let (path, body) = text.split_once('\n').unwrap_or((text, "{}"));
+    if let Ok(json) = serde_json::from_str(body) {
+        let _ = HttpProvider::new().select_json_field(&json, path);
+    }
+}
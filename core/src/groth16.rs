@@ -0,0 +1,374 @@
+//! # Off-chain Groth16 Verification
+//!
+//! Pure-Rust BN254 Groth16 verification, so a proof can be checked before it
+//! is submitted to any chain. The pairing equation is the same one every
+//! on-chain verifier evaluates:
+//!
+//! ```text
+//! e(A, B) = e(alpha, beta) * e(sum(IC_i * x_i), gamma) * e(C, delta)
+//! ```
+//!
+//! ## Byte Layout
+//!
+//! Points use the uncompressed EVM (EIP-197) encoding shared by the Solidity,
+//! Solana `alt_bn128` and SP1 verifiers: every coordinate is a 32-byte
+//! big-endian field element, G2 coordinates are written imaginary part first
+//! (`x.c1, x.c0, y.c1, y.c0`), and the point at infinity is all zeros.
+//!
+//! - **Proof** (256 bytes): `A (G1) || B (G2) || C (G1)`. SP1's on-chain
+//!   proof bytes prefix this with a 4-byte verifier selector, which
+//!   [`Groth16Proof::from_bytes`] strips.
+//! - **Verifying key** (448 + 64·n bytes): `alpha (G1) || beta (G2) ||
+//!   gamma (G2) || delta (G2) || IC_0 .. IC_n (G1)`, one `IC` point per
+//!   public input plus one.
+//!
+//! SP1 programs expose two public inputs, built by [`sp1_public_inputs`].
+
+use ark_bn254::{Bn254, Fq, Fq2, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInt, BigInteger, PrimeField, Zero};
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, VerifyingKey};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+pub use ark_bn254::Fr;
+
+/// Size of one encoded base field element
+const FQ_BYTES: usize = 32;
+
+/// Size of an encoded G1 point
+const G1_BYTES: usize = 2 * FQ_BYTES;
+
+/// Size of an encoded G2 point
+const G2_BYTES: usize = 4 * FQ_BYTES;
+
+/// Size of an encoded proof, without the SP1 selector
+pub const PROOF_BYTES: usize = G1_BYTES + G2_BYTES + G1_BYTES;
+
+/// Size of the SP1 verifier selector prefixed to on-chain proof bytes
+pub const SP1_SELECTOR_BYTES: usize = 4;
+
+/// Size of an encoded verifying key with no `IC` points
+const VKEY_FIXED_BYTES: usize = G1_BYTES + 3 * G2_BYTES;
+
+/// Errors from parsing or verifying Groth16 data
+#[derive(Debug, Error)]
+pub enum Groth16Error {
+    /// Input is not a valid encoding length
+    #[error("Invalid {what} length: {len} bytes")]
+    InvalidLength { what: &'static str, len: usize },
+
+    /// Coordinate is not a canonical field element, or the point is not on
+    /// the curve / in the prime-order subgroup
+    #[error("Invalid {0} point")]
+    InvalidPoint(&'static str),
+
+    /// Number of public inputs doesn't match the verifying key
+    #[error("Expected {expected} public inputs, got {got}")]
+    InputCountMismatch { expected: usize, got: usize },
+
+    /// Pairing library error
+    #[error("Verification failed: {0}")]
+    Verification(String),
+}
+
+/// Groth16 verifying key over BN254
+#[derive(Debug, Clone, PartialEq)]
+pub struct Groth16Vkey(pub VerifyingKey<Bn254>);
+
+/// Groth16 proof over BN254
+#[derive(Debug, Clone, PartialEq)]
+pub struct Groth16Proof(pub Proof<Bn254>);
+
+impl Groth16Vkey {
+    /// Parse a verifying key in the layout described in the module docs
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Groth16Error> {
+        let ic_bytes = bytes.len().checked_sub(VKEY_FIXED_BYTES);
+        if !ic_bytes.is_some_and(|n| n >= G1_BYTES && n % G1_BYTES == 0) {
+            return Err(Groth16Error::InvalidLength { what: "verifying key", len: bytes.len() });
+        }
+
+        let (alpha, rest) = bytes.split_at(G1_BYTES);
+        let (beta, rest) = rest.split_at(G2_BYTES);
+        let (gamma, rest) = rest.split_at(G2_BYTES);
+        let (delta, ic) = rest.split_at(G2_BYTES);
+
+        Ok(Self(VerifyingKey {
+            alpha_g1: read_g1(alpha, "alpha")?,
+            beta_g2: read_g2(beta, "beta")?,
+            gamma_g2: read_g2(gamma, "gamma")?,
+            delta_g2: read_g2(delta, "delta")?,
+            gamma_abc_g1: ic
+                .chunks(G1_BYTES)
+                .map(|point| read_g1(point, "IC"))
+                .collect::<Result<_, _>>()?,
+        }))
+    }
+
+    /// Encode in the layout accepted by `from_bytes`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let vk = &self.0;
+        let mut out = Vec::with_capacity(VKEY_FIXED_BYTES + G1_BYTES * vk.gamma_abc_g1.len());
+        write_g1(&mut out, &vk.alpha_g1);
+        write_g2(&mut out, &vk.beta_g2);
+        write_g2(&mut out, &vk.gamma_g2);
+        write_g2(&mut out, &vk.delta_g2);
+        for point in &vk.gamma_abc_g1 {
+            write_g1(&mut out, point);
+        }
+        out
+    }
+
+    /// Number of public inputs this key verifies
+    pub fn public_input_count(&self) -> usize {
+        self.0.gamma_abc_g1.len() - 1
+    }
+}
+
+impl Groth16Proof {
+    /// Parse a 256-byte proof, or 260 bytes with the SP1 selector prefix
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Groth16Error> {
+        let bytes = match bytes.len() {
+            PROOF_BYTES => bytes,
+            n if n == SP1_SELECTOR_BYTES + PROOF_BYTES => &bytes[SP1_SELECTOR_BYTES..],
+            len => return Err(Groth16Error::InvalidLength { what: "proof", len }),
+        };
+
+        let (a, rest) = bytes.split_at(G1_BYTES);
+        let (b, c) = rest.split_at(G2_BYTES);
+        Ok(Self(Proof {
+            a: read_g1(a, "A")?,
+            b: read_g2(b, "B")?,
+            c: read_g1(c, "C")?,
+        }))
+    }
+
+    /// Encode as 256 bytes, without the SP1 selector
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(PROOF_BYTES);
+        write_g1(&mut out, &self.0.a);
+        write_g2(&mut out, &self.0.b);
+        write_g1(&mut out, &self.0.c);
+        out
+    }
+}
+
+/// Check `proof` against `vkey` for the given public inputs
+///
+/// Returns `Ok(false)` when the pairing equation doesn't hold, and an error
+/// only when the inputs can't be checked at all.
+///
+/// # Errors
+///
+/// Returns `Groth16Error::InputCountMismatch` if `public_inputs` doesn't
+/// have one entry per non-constant `IC` point of `vkey`
+pub fn verify_groth16(
+    vkey: &Groth16Vkey,
+    proof: &Groth16Proof,
+    public_inputs: &[Fr],
+) -> Result<bool, Groth16Error> {
+    let expected = vkey.public_input_count();
+    if public_inputs.len() != expected {
+        return Err(Groth16Error::InputCountMismatch { expected, got: public_inputs.len() });
+    }
+
+    let pvk = PreparedVerifyingKey::from(vkey.0.clone());
+    Groth16::<Bn254>::verify_proof(&pvk, &proof.0, public_inputs)
+        .map_err(|e| Groth16Error::Verification(e.to_string()))
+}
+
+/// Public inputs of an SP1 Groth16 proof: the program vkey hash and the
+/// digest of the committed public values
+///
+/// The digest is `sha256(public_values)` with its top 3 bits cleared so it
+/// fits in the scalar field, matching SP1's verifier contracts.
+pub fn sp1_public_inputs(program_vkey_hash: &[u8; 32], public_values: &[u8]) -> [Fr; 2] {
+    let mut digest: [u8; 32] = Sha256::digest(public_values).into();
+    digest[0] &= 0x1f;
+    [
+        Fr::from_be_bytes_mod_order(program_vkey_hash),
+        Fr::from_be_bytes_mod_order(&digest),
+    ]
+}
+
+/// Read a canonical big-endian base field element
+fn read_fq(bytes: &[u8], what: &'static str) -> Result<Fq, Groth16Error> {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().expect("8-byte chunk"));
+    }
+    Fq::from_bigint(BigInt(limbs)).ok_or(Groth16Error::InvalidPoint(what))
+}
+
+fn read_g1(bytes: &[u8], what: &'static str) -> Result<G1Affine, Groth16Error> {
+    let x = read_fq(&bytes[..FQ_BYTES], what)?;
+    let y = read_fq(&bytes[FQ_BYTES..], what)?;
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1Affine::identity());
+    }
+
+    let point = G1Affine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Groth16Error::InvalidPoint(what));
+    }
+    Ok(point)
+}
+
+fn read_g2(bytes: &[u8], what: &'static str) -> Result<G2Affine, Groth16Error> {
+    let mut coords = [Fq::zero(); 4];
+    for (coord, chunk) in coords.iter_mut().zip(bytes.chunks(FQ_BYTES)) {
+        *coord = read_fq(chunk, what)?;
+    }
+    let [x_c1, x_c0, y_c1, y_c0] = coords;
+    let x = Fq2::new(x_c0, x_c1);
+    let y = Fq2::new(y_c0, y_c1);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2Affine::identity());
+    }
+
+    let point = G2Affine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Groth16Error::InvalidPoint(what));
+    }
+    Ok(point)
+}
+
+fn write_fq(out: &mut Vec<u8>, value: &Fq) {
+    out.extend_from_slice(&value.into_bigint().to_bytes_be());
+}
+
+fn write_g1(out: &mut Vec<u8>, point: &G1Affine) {
+    match point.xy() {
+        Some((x, y)) => {
+            write_fq(out, x);
+            write_fq(out, y);
+        }
+        None => out.extend_from_slice(&[0u8; G1_BYTES]),
+    }
+}
+
+fn write_g2(out: &mut Vec<u8>, point: &G2Affine) {
+    match point.xy() {
+        Some((x, y)) => {
+            for coord in [&x.c1, &x.c0, &y.c1, &y.c0] {
+                write_fq(out, coord);
+            }
+        }
+        None => out.extend_from_slice(&[0u8; G2_BYTES]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_relations::lc;
+    use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Proves knowledge of `x, y` with `x * y = z` for public `z`
+    struct MulCircuit {
+        x: Option<Fr>,
+        y: Option<Fr>,
+    }
+
+    impl ConstraintSynthesizer<Fr> for MulCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            let x_val = self.x.ok_or(SynthesisError::AssignmentMissing);
+            let y_val = self.y.ok_or(SynthesisError::AssignmentMissing);
+            let x = cs.new_witness_variable(|| x_val)?;
+            let y = cs.new_witness_variable(|| y_val)?;
+            let z = cs.new_input_variable(|| Ok(x_val? * y_val?))?;
+            cs.enforce_constraint(lc!() + x, lc!() + y, lc!() + z)
+        }
+    }
+
+    /// Fixed-seed fixture: encoded vkey and proof for `3 * 11 = 33`
+    fn fixture() -> (Vec<u8>, Vec<u8>) {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let circuit = || MulCircuit { x: Some(Fr::from(3u64)), y: Some(Fr::from(11u64)) };
+
+        let pk = Groth16::<Bn254>::generate_random_parameters_with_reduction(circuit(), &mut rng).unwrap();
+        let proof = Groth16::<Bn254>::create_random_proof_with_reduction(circuit(), &pk, &mut rng).unwrap();
+        (Groth16Vkey(pk.vk).to_bytes(), Groth16Proof(proof).to_bytes())
+    }
+
+    #[test]
+    fn test_fixture_proof_verifies() {
+        let (vkey_bytes, proof_bytes) = fixture();
+        assert_eq!(vkey_bytes.len(), VKEY_FIXED_BYTES + 2 * G1_BYTES);
+        assert_eq!(proof_bytes.len(), PROOF_BYTES);
+
+        let vkey = Groth16Vkey::from_bytes(&vkey_bytes).unwrap();
+        let proof = Groth16Proof::from_bytes(&proof_bytes).unwrap();
+        assert_eq!(vkey.public_input_count(), 1);
+
+        assert!(verify_groth16(&vkey, &proof, &[Fr::from(33u64)]).unwrap());
+        assert!(!verify_groth16(&vkey, &proof, &[Fr::from(34u64)]).unwrap());
+    }
+
+    #[test]
+    fn test_sp1_selector_prefix_is_stripped() {
+        let (vkey_bytes, proof_bytes) = fixture();
+        let mut onchain = vec![0x11, 0xb6, 0xa0, 0x9d];
+        onchain.extend_from_slice(&proof_bytes);
+
+        let vkey = Groth16Vkey::from_bytes(&vkey_bytes).unwrap();
+        let proof = Groth16Proof::from_bytes(&onchain).unwrap();
+        assert_eq!(proof.to_bytes(), proof_bytes);
+        assert!(verify_groth16(&vkey, &proof, &[Fr::from(33u64)]).unwrap());
+    }
+
+    #[test]
+    fn test_tampered_proof_fails() {
+        let (vkey_bytes, proof_bytes) = fixture();
+        let vkey = Groth16Vkey::from_bytes(&vkey_bytes).unwrap();
+
+        // Swapping A and C keeps both points valid but breaks the equation
+        let mut swapped = proof_bytes.clone();
+        swapped[..G1_BYTES].copy_from_slice(&proof_bytes[G1_BYTES + G2_BYTES..]);
+        swapped[G1_BYTES + G2_BYTES..].copy_from_slice(&proof_bytes[..G1_BYTES]);
+        let proof = Groth16Proof::from_bytes(&swapped).unwrap();
+        assert!(!verify_groth16(&vkey, &proof, &[Fr::from(33u64)]).unwrap());
+
+        // Flipping a coordinate byte takes A off the curve
+        let mut corrupt = proof_bytes;
+        corrupt[31] ^= 1;
+        assert!(matches!(Groth16Proof::from_bytes(&corrupt), Err(Groth16Error::InvalidPoint("A"))));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let (vkey_bytes, proof_bytes) = fixture();
+        let vkey = Groth16Vkey::from_bytes(&vkey_bytes).unwrap();
+        let proof = Groth16Proof::from_bytes(&proof_bytes).unwrap();
+
+        assert!(matches!(
+            Groth16Proof::from_bytes(&proof_bytes[..255]),
+            Err(Groth16Error::InvalidLength { what: "proof", len: 255 })
+        ));
+        assert!(matches!(
+            Groth16Vkey::from_bytes(&vkey_bytes[..VKEY_FIXED_BYTES]),
+            Err(Groth16Error::InvalidLength { what: "verifying key", .. })
+        ));
+        assert!(matches!(
+            verify_groth16(&vkey, &proof, &[]),
+            Err(Groth16Error::InputCountMismatch { expected: 1, got: 0 })
+        ));
+
+        // A coordinate at or above the field modulus is not canonical
+        let mut non_canonical = proof_bytes;
+        non_canonical[..FQ_BYTES].fill(0xff);
+        assert!(matches!(Groth16Proof::from_bytes(&non_canonical), Err(Groth16Error::InvalidPoint("A"))));
+    }
+
+    #[test]
+    fn test_sp1_public_inputs() {
+        let vkey_hash = [0x00; 32];
+        let [program, digest] = sp1_public_inputs(&vkey_hash, b"public values");
+        assert_eq!(program, Fr::zero());
+
+        let mut expected: [u8; 32] = Sha256::digest(b"public values").into();
+        expected[0] &= 0x1f;
+        assert_eq!(digest.into_bigint().to_bytes_be(), expected.to_vec());
+    }
+}
@@ -12,7 +12,11 @@
 //! 3. **Testability**: Mock implementations for unit testing without real provers
 //! 4. **Future-Proofing**: New backends can be added without modifying core abstractions
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 // RWA (Real-World Asset) compliance types
@@ -30,11 +34,31 @@ pub mod data_source;
 // Notary service for ECDSA signing (EVM compatibility)
 pub mod notary;
 
+// Chain-specific calldata encoding for proof receipts
+pub mod calldata;
+
+// Offline on-chain verification cost heuristics
+pub mod cost;
+
+// Off-chain BN254 Groth16 verification
+pub mod groth16;
+
+// Memoized verification keyed by receipt fingerprint
+pub mod verification_cache;
+
+// Entry points for `cargo fuzz` targets (see core/fuzz)
+#[cfg(fuzzing)]
+pub mod fuzzing;
+
+// Reusable PrivacyEngine mock for tests and downstream crates
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 /// Represents the target blockchain platform for verifier deployment.
 ///
 /// This enum allows the `PrivacyEngine` to generate chain-specific verifier bytecode,
 /// accounting for differences in VM architectures, gas models, and calling conventions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub enum ChainType {
     /// Solana - High-performance blockchain with BPF VM
     Solana,
@@ -44,6 +68,57 @@ pub enum ChainType {
     
     /// EVM - Ethereum Virtual Machine compatible chains
     Evm,
+
+    /// CosmWasm - WASM smart contracts on Cosmos SDK chains (e.g. Mantra)
+    Cosmwasm,
+}
+
+/// Errors parsing a chain name with `ChainType::parse`
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ChainParseError {
+    /// The name doesn't match any known chain or alias
+    #[error("unknown chain '{0}' (expected one of: solana, stellar, evm, mantra-evm, mantra-cosmwasm, cosmwasm)")]
+    Unknown(String),
+}
+
+impl ChainType {
+    /// Parse a chain name, accepting every alias used by the CLI, MCP tools and
+    /// orchestrator.
+    ///
+    /// Matching is case-insensitive and treats `_` like `-`:
+    ///
+    /// | Alias | Chain |
+    /// |-------|-------|
+    /// | `solana` | `Solana` |
+    /// | `stellar` | `Stellar` |
+    /// | `evm`, `mantra-evm` | `Evm` |
+    /// | `cosmwasm`, `mantra-cosmwasm` | `Cosmwasm` |
+    pub fn parse(s: &str) -> Result<Self, ChainParseError> {
+        let normalized = s.trim().to_ascii_lowercase().replace('_', "-");
+        match normalized.as_str() {
+            "solana" => Ok(ChainType::Solana),
+            "stellar" => Ok(ChainType::Stellar),
+            "evm" | "mantra-evm" => Ok(ChainType::Evm),
+            "cosmwasm" | "mantra-cosmwasm" => Ok(ChainType::Cosmwasm),
+            _ => Err(ChainParseError::Unknown(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for ChainType {
+    type Error = ChainParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        ChainType::parse(s)
+    }
+}
+
+impl std::str::FromStr for ChainType {
+    type Err = ChainParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ChainType::parse(s)
+    }
 }
 
 /// Distinguishes between different types of cryptographic proofs.
@@ -63,6 +138,34 @@ pub enum ProofType {
     /// These attestations prove that computation occurred within a secure enclave.
     /// Typical size: 1-5KB (includes signature and enclave measurements)
     TeeAttestation,
+
+    /// Groth16 SNARK, directly verifiable by the on-chain verifiers
+    ///
+    /// Equivalent to a `ZkProof` whose metadata `mode` is `groth16`; see
+    /// `ProofType::from_mode`. Typical size: 260 bytes (SP1 selector + proof)
+    Groth16,
+}
+
+impl ProofType {
+    /// Proof type for a receipt whose metadata records proving `mode`.
+    ///
+    /// `groth16` (any case) maps to `Groth16`; every other zkVM mode
+    /// (`stark`, `compressed`, `core`, `mock`, ...) maps to `ZkProof`.
+    pub fn from_mode(mode: &str) -> Self {
+        if mode.eq_ignore_ascii_case("groth16") {
+            ProofType::Groth16
+        } else {
+            ProofType::ZkProof
+        }
+    }
+
+    /// Metadata `mode` implied by this proof type, if it implies one
+    pub fn mode(&self) -> Option<&'static str> {
+        match self {
+            ProofType::Groth16 => Some("groth16"),
+            ProofType::ZkProof | ProofType::TeeAttestation => None,
+        }
+    }
 }
 
 /// A serializable proof receipt containing the ZK proof and associated metadata.
@@ -87,6 +190,257 @@ pub struct ProofReceipt {
     pub metadata: Vec<u8>,
 }
 
+/// Metadata key for the executed zkVM cycle count
+pub const METADATA_CYCLES_KEY: &str = "cycles";
+
+/// Metadata key for guest execution time in milliseconds, excluding setup and proving
+pub const METADATA_EXECUTION_TIME_KEY: &str = "execution_time_ms";
+
+/// Metadata keys retained by `ProofReceipt::minimized`
+pub const MINIMIZED_METADATA_KEYS: &[&str] = &["proof_system", "mode", "vkey_hash"];
+
+/// Current `ProofMetadata` schema version
+///
+/// Version 1 is the untagged JSON written before metadata was typed: no
+/// `schema_version`, with the backend under `proof_system`.
+pub const PROOF_METADATA_VERSION: u16 = 2;
+
+/// Errors parsing `ProofReceipt::metadata` with `ProofMetadata::parse`
+#[derive(Debug, Error)]
+pub enum ProofMetadataError {
+    /// Metadata is not a JSON object
+    #[error("invalid proof metadata: {0}")]
+    Invalid(String),
+
+    /// Metadata was written by a newer schema than this build understands
+    #[error("unsupported proof metadata version {found} (latest supported: {supported})")]
+    UnsupportedVersion { found: u16, supported: u16 },
+}
+
+/// Typed view of `ProofReceipt::metadata`
+///
+/// `parse` accepts every schema version up to `PROOF_METADATA_VERSION` and
+/// migrates it to the current layout; `to_bytes` always writes the current
+/// version. Keys not covered by a field (e.g. `vkey_hash`) are kept in
+/// `extra` so a parse/serialize round trip is lossless.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProofMetadata {
+    /// Schema version the metadata was written with
+    pub schema_version: u16,
+
+    /// Proving backend that produced the receipt (e.g. `"sp1"`)
+    pub backend: String,
+
+    /// Proving mode (e.g. `"groth16"`, `"mock"`)
+    pub mode: String,
+
+    /// Executed zkVM cycle count, for backends that report it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycles: Option<u64>,
+
+    /// Any other metadata keys
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ProofMetadata {
+    /// Metadata in the current schema for `backend` proving in `mode`
+    pub fn new(backend: impl Into<String>, mode: impl Into<String>) -> Self {
+        Self {
+            schema_version: PROOF_METADATA_VERSION,
+            backend: backend.into(),
+            mode: mode.into(),
+            cycles: None,
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// Record the executed cycle count
+    pub fn with_cycles(mut self, cycles: u64) -> Self {
+        self.cycles = Some(cycles);
+        self
+    }
+
+    /// Parse metadata of any supported schema version
+    ///
+    /// Missing `backend` or `mode` become `"unknown"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProofMetadataError::Invalid` for non-object metadata and
+    /// `ProofMetadataError::UnsupportedVersion` for versions newer than
+    /// `PROOF_METADATA_VERSION`
+    pub fn parse(bytes: &[u8]) -> Result<Self, ProofMetadataError> {
+        let mut map = match serde_json::from_slice::<serde_json::Value>(bytes) {
+            Ok(serde_json::Value::Object(map)) => map,
+            Ok(_) => return Err(ProofMetadataError::Invalid("expected a JSON object".into())),
+            Err(e) => return Err(ProofMetadataError::Invalid(e.to_string())),
+        };
+
+        let version = match map.remove("schema_version") {
+            None => 1,
+            Some(v) => v
+                .as_u64()
+                .and_then(|v| u16::try_from(v).ok())
+                .ok_or_else(|| ProofMetadataError::Invalid(format!("bad schema_version: {}", v)))?,
+        };
+        if version > PROOF_METADATA_VERSION {
+            return Err(ProofMetadataError::UnsupportedVersion {
+                found: version,
+                supported: PROOF_METADATA_VERSION,
+            });
+        }
+
+        // v1 -> v2: the backend moved from `proof_system` to `backend`
+        if version < 2 && !map.contains_key("backend") {
+            if let Some(proof_system) = map.remove("proof_system") {
+                map.insert("backend".into(), proof_system);
+            }
+        }
+
+        let mut take_string = |key: &str| match map.remove(key) {
+            Some(serde_json::Value::String(s)) => Ok(s),
+            None => Ok("unknown".to_string()),
+            Some(other) => Err(ProofMetadataError::Invalid(format!("'{}' must be a string, got {}", key, other))),
+        };
+        let backend = take_string("backend")?;
+        let mode = take_string("mode")?;
+        let cycles = match map.remove(METADATA_CYCLES_KEY) {
+            None | Some(serde_json::Value::Null) => None,
+            Some(v) => Some(v.as_u64().ok_or_else(|| {
+                ProofMetadataError::Invalid(format!("'{}' must be an unsigned integer, got {}", METADATA_CYCLES_KEY, v))
+            })?),
+        };
+
+        Ok(Self {
+            schema_version: PROOF_METADATA_VERSION,
+            backend,
+            mode,
+            cycles,
+            extra: map,
+        })
+    }
+
+    /// Serialize as current-version JSON for `ProofReceipt::metadata`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let current = Self { schema_version: PROOF_METADATA_VERSION, ..self.clone() };
+        serde_json::to_vec(&current).expect("ProofMetadata is always serializable")
+    }
+}
+
+impl ProofReceipt {
+    /// SHA256 fingerprint of the proof's verifiable content.
+    ///
+    /// Covers the proof type, proof bytes and public values (each length
+    /// prefixed) but not `metadata`, so a receipt keeps its fingerprint after
+    /// `minimized()`.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        hasher.update([match self.proof_type {
+            ProofType::ZkProof => 0u8,
+            ProofType::TeeAttestation => 1u8,
+            ProofType::Groth16 => 2u8,
+        }]);
+        for part in [&self.proof, &self.public_values] {
+            hasher.update((part.len() as u64).to_le_bytes());
+            hasher.update(part);
+        }
+        hasher.finalize().into()
+    }
+
+    /// Typed metadata, migrated from older schema versions
+    pub fn parsed_metadata(&self) -> Result<ProofMetadata, ProofMetadataError> {
+        ProofMetadata::parse(&self.metadata)
+    }
+
+    /// Executed zkVM cycle count recorded in JSON metadata under `METADATA_CYCLES_KEY`, if any.
+    pub fn metadata_cycles(&self) -> Option<u64> {
+        self.parsed_metadata().ok()?.cycles
+    }
+
+    /// Guest execution time recorded in JSON metadata under `METADATA_EXECUTION_TIME_KEY`, if any.
+    pub fn metadata_execution_time(&self) -> Option<Duration> {
+        self.metadata_u64(METADATA_EXECUTION_TIME_KEY).map(Duration::from_millis)
+    }
+
+    fn metadata_u64(&self, key: &str) -> Option<u64> {
+        let metadata: serde_json::Value = serde_json::from_slice(&self.metadata).ok()?;
+        metadata.get(key)?.as_u64()
+    }
+
+    /// Copy of this receipt with metadata reduced to what verification needs.
+    ///
+    /// JSON metadata keeps only `MINIMIZED_METADATA_KEYS`, dropping internal
+    /// details such as prover URLs or machine info before a proof is shared.
+    /// Non-JSON metadata is dropped entirely.
+    pub fn minimized(&self) -> ProofReceipt {
+        let metadata = match serde_json::from_slice::<serde_json::Value>(&self.metadata) {
+            Ok(serde_json::Value::Object(map)) => {
+                let kept: serde_json::Map<_, _> = map
+                    .into_iter()
+                    .filter(|(key, _)| MINIMIZED_METADATA_KEYS.contains(&key.as_str()))
+                    .collect();
+                serde_json::to_vec(&kept).unwrap_or_default()
+            }
+            _ => Vec::new(),
+        };
+
+        ProofReceipt {
+            proof_type: self.proof_type,
+            proof: self.proof.clone(),
+            public_values: self.public_values.clone(),
+            metadata,
+        }
+    }
+}
+
+/// Detailed outcome of verifying a proof receipt.
+///
+/// Bundles everything a caller typically needs after verification (validity,
+/// the committed public values, their decoded RWA form, and timing) so that
+/// CLIs and HTTP endpoints don't have to re-derive it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationReport {
+    /// Whether the proof verified successfully
+    pub valid: bool,
+
+    /// Raw public values committed to in the proof
+    pub public_values: Vec<u8>,
+
+    /// Public values decoded as `RwaPublicValues`, if they match that layout
+    pub decoded: Option<rwa::RwaPublicValues>,
+
+    /// Wall-clock time spent in verification
+    pub duration: Duration,
+
+    /// Hash of the verifying key the receipt was checked against
+    pub vkey_hash: [u8; 32],
+}
+
+/// Structured figures from a single proof generation.
+///
+/// Returned by `PrivacyEngine::prove_with_stats` so CLIs, agents and
+/// benchmarks can report sizes and timing without scraping logs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvingStats {
+    /// Size of `ProofReceipt::proof` in bytes
+    pub proof_bytes: usize,
+
+    /// Size of `ProofReceipt::public_values` in bytes
+    pub public_value_bytes: usize,
+
+    /// zkVM cycle count, for backends that report it
+    pub cycles: Option<u64>,
+
+    /// Wall-clock time spent proving
+    pub duration: Duration,
+
+    /// Proving mode from the receipt metadata or its `proof_type` (e.g. `"groth16"`), if any
+    pub mode: Option<String>,
+}
+
 /// Core trait defining the interface for all ZK proving backends.
 ///
 /// ## Design Rationale
@@ -98,6 +452,7 @@ pub struct ProofReceipt {
 ///
 /// By keeping this interface minimal and focused, we ensure that any ZK backend
 /// can implement it without requiring extensive adapter code.
+#[async_trait]
 pub trait PrivacyEngine {
     /// Generate a zero-knowledge proof from the provided input data.
     ///
@@ -129,6 +484,127 @@ pub trait PrivacyEngine {
     /// Returns `PrivacyEngineError::VerificationFailed` if verification process fails
     fn verify(&self, receipt: &ProofReceipt) -> Result<bool, PrivacyEngineError>;
 
+    /// Verify a list of proof receipts, returning one result per receipt.
+    ///
+    /// The default implementation calls `verify` sequentially. Backends with
+    /// expensive per-call setup (e.g., deserializing a verifying key) should
+    /// override this to share that state across the whole batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `receipts` - The proof receipts to verify
+    ///
+    /// # Returns
+    ///
+    /// A vector with the same length and order as `receipts`, where each entry
+    /// is the outcome of verifying the corresponding receipt
+    fn verify_batch(&self, receipts: &[ProofReceipt]) -> Vec<Result<bool, PrivacyEngineError>> {
+        receipts.iter().map(|receipt| self.verify(receipt)).collect()
+    }
+
+    /// Verify a list of proof receipts, stopping at the first malformed one.
+    ///
+    /// Unlike `verify_batch`, a verification error aborts the whole batch,
+    /// which suits indexers that treat a malformed proof as a fault rather
+    /// than a failed check. Invalid-but-well-formed proofs still yield `false`
+    /// at their index, so callers can tell which proof failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced by `verify`
+    fn verify_batch_strict(&self, receipts: &[ProofReceipt]) -> Result<Vec<bool>, PrivacyEngineError> {
+        receipts.iter().map(|receipt| self.verify(receipt)).collect()
+    }
+
+    /// Generate a proof and report its sizes and timing.
+    ///
+    /// `cycles` is read from the receipt's `"cycles"` metadata, so backends
+    /// that record it there get it reported without overriding this.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error returned by `prove`
+    fn prove_with_stats(&self, input: &[u8]) -> Result<(ProofReceipt, ProvingStats), PrivacyEngineError> {
+        let start = Instant::now();
+        let receipt = self.prove(input)?;
+        let stats = ProvingStats {
+            proof_bytes: receipt.proof.len(),
+            public_value_bytes: receipt.public_values.len(),
+            cycles: receipt.metadata_cycles(),
+            duration: start.elapsed(),
+            mode: receipt.proving_mode(),
+        };
+        Ok((receipt, stats))
+    }
+
+    /// Generate a proof without blocking the async runtime.
+    ///
+    /// The default runs `prove` on tokio's blocking thread pool. That pool
+    /// needs owned, `'static` data, so the engine is taken behind an `Arc`
+    /// and the input by value. Backends with a natively async prover should
+    /// override this.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error returned by `prove`, or
+    /// `PrivacyEngineError::ProvingFailed` if the blocking task panics or is
+    /// cancelled
+    async fn prove_async(self: Arc<Self>, input: Vec<u8>) -> Result<ProofReceipt, PrivacyEngineError>
+    where
+        Self: Send + Sync + 'static,
+    {
+        tokio::task::spawn_blocking(move || self.prove(&input))
+            .await
+            .map_err(|e| PrivacyEngineError::ProvingFailed(format!("Proving task failed: {}", e)))?
+    }
+
+    /// Verify a proof receipt and return a detailed report.
+    ///
+    /// `verify` remains the primitive; this wraps it with timing, the
+    /// backend's verifying key hash, and a best-effort decode of the public
+    /// values as `RwaPublicValues`.
+    ///
+    /// # Arguments
+    ///
+    /// * `receipt` - The proof receipt to verify
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error returned by `verify`
+    fn verify_with_report(&self, receipt: &ProofReceipt) -> Result<VerificationReport, PrivacyEngineError> {
+        let start = Instant::now();
+        let valid = self.verify(receipt)?;
+        let duration = start.elapsed();
+
+        Ok(VerificationReport {
+            valid,
+            public_values: receipt.public_values.clone(),
+            decoded: rwa::RwaPublicValues::try_from_slice(&receipt.public_values).ok(),
+            duration,
+            vkey_hash: self.vkey_hash(),
+        })
+    }
+
+    /// Hash of the verifying key used by this backend.
+    ///
+    /// Backends without a verifying key (mocks, TEE stubs) return all zeros.
+    fn vkey_hash(&self) -> [u8; 32] {
+        [0u8; 32]
+    }
+
+    /// Short identifier of the proving backend (e.g. `"sp1"`, `"sgx"`).
+    ///
+    /// Used in logs, metrics and receipt metadata to record which backend
+    /// produced a proof.
+    fn backend_name(&self) -> &'static str {
+        "unknown"
+    }
+
+    /// Version of the proving backend or its SDK.
+    fn backend_version(&self) -> &'static str {
+        "unknown"
+    }
+
     /// Export a verifier contract/program for the specified blockchain.
     ///
     /// This method generates chain-specific bytecode that can verify proofs on-chain.
@@ -136,6 +612,7 @@ pub trait PrivacyEngine {
     /// - **Solana**: BPF bytecode for a Solana program
     /// - **Stellar**: WASM for Soroban contract
     /// - **EVM**: Solidity contract bytecode
+    /// - **CosmWasm**: WASM for a CosmWasm contract
     ///
     /// # Arguments
     ///
@@ -149,6 +626,23 @@ pub trait PrivacyEngine {
     ///
     /// Returns `PrivacyEngineError::ExportFailed` if verifier generation fails
     fn export_verifier(&self, chain: ChainType) -> Result<Vec<u8>, PrivacyEngineError>;
+
+    /// Export a verifier together with an estimate of what each verification costs.
+    ///
+    /// The default reports the SP1 Groth16 figures from
+    /// `cost::VerifierReport::sp1_groth16`; backends with a different proof
+    /// system should override it.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error returned by `export_verifier`
+    fn export_verifier_with_report(
+        &self,
+        chain: ChainType,
+    ) -> Result<(Vec<u8>, cost::VerifierReport), PrivacyEngineError> {
+        let bytecode = self.export_verifier(chain)?;
+        Ok((bytecode, cost::VerifierReport::sp1_groth16(chain)))
+    }
 }
 
 /// Comprehensive error types for the Privacy Engine.
@@ -204,12 +698,120 @@ pub enum PrivacyEngineError {
     /// (e.g., Borsh, Bincode, JSON) fails.
     #[error("Serialization error: {0}")]
     SerializationError(String),
+
+    /// Backend setup failed (e.g. guest ELF missing, key generation failed)
+    #[error("Setup failed: {0}")]
+    SetupFailed(String),
+
+    /// The prover ran out of memory
+    #[error("Out of memory: {0}")]
+    OutOfMemory(String),
+
+    /// Proof bytes could not be decoded into the expected proof format
+    #[error("Invalid proof format: {0}")]
+    InvalidProofFormat(String),
+
+    /// Proof was generated for a different verifying key
+    #[error("Verifying key mismatch: expected {expected}, got {got}")]
+    VkeyMismatch {
+        expected: String,
+        got: String,
+    },
+}
+
+/// Machine-readable category of a `PrivacyEngineError`
+///
+/// Lets the CLI and orchestrator branch on the failure without matching on
+/// message strings, e.g. to retry only transient failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ErrorKind {
+    ProvingFailed,
+    VerificationFailed,
+    ExportFailed,
+    InvalidInput,
+    Backend,
+    AttestationInvalid,
+    Enclave,
+    Serialization,
+    SetupFailed,
+    OutOfMemory,
+    InvalidProofFormat,
+    VkeyMismatch,
+}
+
+impl ErrorKind {
+    /// Whether the same call may succeed if retried
+    ///
+    /// Backend and enclave errors cover flaky provers and remote services;
+    /// out-of-memory can clear once concurrent proving jobs finish.
+    pub fn is_transient(self) -> bool {
+        matches!(self, ErrorKind::Backend | ErrorKind::Enclave | ErrorKind::OutOfMemory)
+    }
+}
+
+impl PrivacyEngineError {
+    /// Category of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            PrivacyEngineError::ProvingFailed(_) => ErrorKind::ProvingFailed,
+            PrivacyEngineError::VerificationFailed(_) => ErrorKind::VerificationFailed,
+            PrivacyEngineError::ExportFailed { .. } => ErrorKind::ExportFailed,
+            PrivacyEngineError::InvalidInput(_) => ErrorKind::InvalidInput,
+            PrivacyEngineError::BackendError(_) => ErrorKind::Backend,
+            PrivacyEngineError::AttestationInvalid(_) => ErrorKind::AttestationInvalid,
+            PrivacyEngineError::EnclaveError(_) => ErrorKind::Enclave,
+            PrivacyEngineError::SerializationError(_) => ErrorKind::Serialization,
+            PrivacyEngineError::SetupFailed(_) => ErrorKind::SetupFailed,
+            PrivacyEngineError::OutOfMemory(_) => ErrorKind::OutOfMemory,
+            PrivacyEngineError::InvalidProofFormat(_) => ErrorKind::InvalidProofFormat,
+            PrivacyEngineError::VkeyMismatch { .. } => ErrorKind::VkeyMismatch,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_error_kinds() {
+        let cases = [
+            (PrivacyEngineError::ProvingFailed("x".into()), ErrorKind::ProvingFailed),
+            (PrivacyEngineError::VerificationFailed("x".into()), ErrorKind::VerificationFailed),
+            (
+                PrivacyEngineError::ExportFailed { chain: ChainType::Evm, reason: "x".into() },
+                ErrorKind::ExportFailed,
+            ),
+            (PrivacyEngineError::InvalidInput("x".into()), ErrorKind::InvalidInput),
+            (PrivacyEngineError::BackendError("x".into()), ErrorKind::Backend),
+            (PrivacyEngineError::AttestationInvalid("x".into()), ErrorKind::AttestationInvalid),
+            (PrivacyEngineError::EnclaveError("x".into()), ErrorKind::Enclave),
+            (PrivacyEngineError::SerializationError("x".into()), ErrorKind::Serialization),
+            (PrivacyEngineError::SetupFailed("ELF not found".into()), ErrorKind::SetupFailed),
+            (PrivacyEngineError::OutOfMemory("x".into()), ErrorKind::OutOfMemory),
+            (PrivacyEngineError::InvalidProofFormat("x".into()), ErrorKind::InvalidProofFormat),
+            (
+                PrivacyEngineError::VkeyMismatch { expected: "aa".into(), got: "bb".into() },
+                ErrorKind::VkeyMismatch,
+            ),
+        ];
+        for (error, kind) in cases {
+            assert_eq!(error.kind(), kind, "{}", error);
+        }
+
+        let transient: Vec<ErrorKind> = [ErrorKind::Backend, ErrorKind::SetupFailed, ErrorKind::OutOfMemory]
+            .into_iter()
+            .filter(|k| k.is_transient())
+            .collect();
+        assert_eq!(transient, [ErrorKind::Backend, ErrorKind::OutOfMemory]);
+
+        // Display stays human-readable
+        assert_eq!(
+            PrivacyEngineError::SetupFailed("ELF not found".into()).to_string(),
+            "Setup failed: ELF not found"
+        );
+    }
+
     #[test]
     fn test_chain_type_serialization() {
         let chain = ChainType::Solana;
@@ -218,6 +820,18 @@ mod tests {
         assert_eq!(chain, deserialized);
     }
 
+    #[test]
+    fn test_cosmwasm_chain_type_round_trip() {
+        let chain = ChainType::Cosmwasm;
+
+        let serialized = bincode::serialize(&chain).unwrap();
+        assert_eq!(bincode::deserialize::<ChainType>(&serialized).unwrap(), chain);
+
+        let json = serde_json::to_string(&chain).unwrap();
+        assert_eq!(json, r#""Cosmwasm""#);
+        assert_eq!(serde_json::from_str::<ChainType>(&json).unwrap(), chain);
+    }
+
     #[test]
     fn test_proof_receipt_serialization() {
         let receipt = ProofReceipt {
@@ -234,5 +848,280 @@ mod tests {
         assert_eq!(receipt.proof, deserialized.proof);
         assert_eq!(receipt.public_values, deserialized.public_values);
         assert_eq!(receipt.metadata, deserialized.metadata);
+
+        let groth16 = ProofReceipt { proof_type: ProofType::Groth16, ..receipt };
+        let deserialized: ProofReceipt = bincode::deserialize(&bincode::serialize(&groth16).unwrap()).unwrap();
+        assert_eq!(deserialized.proof_type, ProofType::Groth16);
+        assert_ne!(groth16.fingerprint(), ProofReceipt { proof_type: ProofType::ZkProof, ..groth16.clone() }.fingerprint());
+    }
+
+    #[test]
+    fn test_proof_type_groth16_round_trip() {
+        for proof_type in [ProofType::ZkProof, ProofType::TeeAttestation, ProofType::Groth16] {
+            let json = serde_json::to_string(&proof_type).unwrap();
+            assert_eq!(serde_json::from_str::<ProofType>(&json).unwrap(), proof_type);
+            let bytes = bincode::serialize(&proof_type).unwrap();
+            assert_eq!(bincode::deserialize::<ProofType>(&bytes).unwrap(), proof_type);
+        }
+        assert_eq!(serde_json::to_string(&ProofType::Groth16).unwrap(), r#""Groth16""#);
+        // Appended last, so existing variants keep their bincode tags
+        assert_eq!(bincode::serialize(&ProofType::TeeAttestation).unwrap(), 1u32.to_le_bytes());
+        assert_eq!(bincode::serialize(&ProofType::Groth16).unwrap(), 2u32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_proof_type_mode_mapping() {
+        assert_eq!(ProofType::from_mode("groth16"), ProofType::Groth16);
+        assert_eq!(ProofType::from_mode("Groth16"), ProofType::Groth16);
+        assert_eq!(ProofType::from_mode("stark"), ProofType::ZkProof);
+        assert_eq!(ProofType::from_mode("mock"), ProofType::ZkProof);
+
+        assert_eq!(ProofType::Groth16.mode(), Some("groth16"));
+        assert_eq!(ProofType::ZkProof.mode(), None);
+        assert_eq!(ProofType::TeeAttestation.mode(), None);
+        assert_eq!(ProofType::from_mode(ProofType::Groth16.mode().unwrap()), ProofType::Groth16);
+    }
+
+    /// Accepts receipts whose proof is `[0xAA]`, rejects other proofs and
+    /// fails outright on an empty proof.
+    struct BatchTestEngine;
+
+    impl PrivacyEngine for BatchTestEngine {
+        fn prove(&self, input: &[u8]) -> Result<ProofReceipt, PrivacyEngineError> {
+            Ok(ProofReceipt {
+                proof_type: ProofType::ZkProof,
+                proof: vec![0xAA],
+                public_values: input.to_vec(),
+                metadata: vec![],
+            })
+        }
+
+        fn verify(&self, receipt: &ProofReceipt) -> Result<bool, PrivacyEngineError> {
+            if receipt.proof.is_empty() {
+                return Err(PrivacyEngineError::VerificationFailed("empty proof".into()));
+            }
+            Ok(receipt.proof == [0xAA])
+        }
+
+        fn export_verifier(&self, _chain: ChainType) -> Result<Vec<u8>, PrivacyEngineError> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_chain_type_parse_aliases() {
+        let cases = [
+            ("solana", ChainType::Solana),
+            ("Solana", ChainType::Solana),
+            ("stellar", ChainType::Stellar),
+            ("evm", ChainType::Evm),
+            ("mantra-evm", ChainType::Evm),
+            ("MANTRA_EVM", ChainType::Evm),
+            ("cosmwasm", ChainType::Cosmwasm),
+            ("mantra-cosmwasm", ChainType::Cosmwasm),
+            ("Mantra_CosmWasm", ChainType::Cosmwasm),
+        ];
+        for (alias, expected) in cases {
+            assert_eq!(ChainType::parse(alias), Ok(expected), "{}", alias);
+        }
+
+        assert_eq!(ChainType::try_from("evm"), Ok(ChainType::Evm));
+        assert_eq!("solana".parse::<ChainType>(), Ok(ChainType::Solana));
+
+        assert!(matches!(ChainType::parse("bitcoin"), Err(ChainParseError::Unknown(_))));
+    }
+
+    #[test]
+    fn test_prove_with_stats_matches_receipt() {
+        let engine = testing::MockEngine::builder()
+            .proof(vec![0xEE; 260])
+            .metadata(br#"{"mode":"Groth16"}"#.to_vec())
+            .build();
+
+        let (receipt, stats) = engine.prove_with_stats(&[1u8; 40]).unwrap();
+
+        assert_eq!(stats.proof_bytes, receipt.proof.len());
+        assert_eq!(stats.proof_bytes, 260);
+        assert_eq!(stats.public_value_bytes, receipt.public_values.len());
+        assert_eq!(stats.public_value_bytes, 40);
+        assert_eq!(stats.mode.as_deref(), Some("groth16"));
+        assert_eq!(stats.cycles, None);
+    }
+
+    #[test]
+    fn test_cycles_and_execution_time_from_metadata() {
+        let engine = testing::MockEngine::builder()
+            .metadata(br#"{"mode":"mock","cycles":123456,"execution_time_ms":42}"#.to_vec())
+            .build();
+
+        let (receipt, stats) = engine.prove_with_stats(b"input").unwrap();
+        assert_eq!(receipt.metadata_cycles(), Some(123456));
+        assert_eq!(receipt.metadata_execution_time(), Some(Duration::from_millis(42)));
+        assert_eq!(stats.cycles, Some(123456));
+
+        let bare = ProofReceipt {
+            proof_type: ProofType::ZkProof,
+            proof: vec![],
+            public_values: vec![],
+            metadata: b"not json".to_vec(),
+        };
+        assert_eq!(bare.metadata_cycles(), None);
+        assert_eq!(bare.metadata_execution_time(), None);
+    }
+
+    #[test]
+    fn test_proof_metadata_migrates_v1() {
+        let v1 = br#"{"proof_system":"sp1","mode":"Groth16","cycles":98765,"vkey_hash":"0xabc"}"#;
+        let metadata = ProofMetadata::parse(v1).unwrap();
+
+        assert_eq!(metadata.schema_version, PROOF_METADATA_VERSION);
+        assert_eq!(metadata.backend, "sp1");
+        assert_eq!(metadata.mode, "Groth16");
+        assert_eq!(metadata.cycles, Some(98765));
+        assert_eq!(metadata.extra["vkey_hash"], "0xabc");
+
+        // Missing fields are tolerated
+        let sparse = ProofMetadata::parse(br#"{"mode":"mock"}"#).unwrap();
+        assert_eq!(sparse.backend, "unknown");
+        assert_eq!(sparse.cycles, None);
+    }
+
+    #[test]
+    fn test_proof_metadata_round_trip() {
+        let mut metadata = ProofMetadata::new("sp1", "groth16").with_cycles(42);
+        metadata.extra.insert("vkey_hash".into(), "0xabc".into());
+
+        let bytes = metadata.to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["schema_version"], PROOF_METADATA_VERSION);
+        assert_eq!(json["vkey_hash"], "0xabc");
+        assert_eq!(ProofMetadata::parse(&bytes).unwrap(), metadata);
+
+        // Upgrading a v1 blob writes the current version
+        let upgraded = ProofMetadata::parse(br#"{"proof_system":"sp1","mode":"core"}"#).unwrap().to_bytes();
+        let reparsed = ProofMetadata::parse(&upgraded).unwrap();
+        assert_eq!(reparsed.backend, "sp1");
+        assert!(!reparsed.extra.contains_key("proof_system"));
+    }
+
+    #[test]
+    fn test_proof_metadata_rejects_bad_input() {
+        assert!(matches!(ProofMetadata::parse(b"mock_engine"), Err(ProofMetadataError::Invalid(_))));
+        assert!(matches!(ProofMetadata::parse(b"[1,2]"), Err(ProofMetadataError::Invalid(_))));
+        assert!(matches!(
+            ProofMetadata::parse(br#"{"schema_version":99,"backend":"sp1","mode":"core"}"#),
+            Err(ProofMetadataError::UnsupportedVersion { found: 99, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_prove_async_matches_sync_prove() {
+        let engine = Arc::new(testing::MockEngine::new());
+
+        let receipt = engine.clone().prove_async(b"witness".to_vec()).await.unwrap();
+
+        let expected = engine.prove(b"witness").unwrap();
+        assert_eq!(receipt.proof, expected.proof);
+        assert_eq!(receipt.public_values, expected.public_values);
+        assert_eq!(engine.prove_calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_prove_async_through_trait_object() {
+        let engine: Arc<dyn PrivacyEngine + Send + Sync> = Arc::new(testing::MockEngine::new());
+
+        let receipt = engine.prove_async(vec![7u8; 4]).await.unwrap();
+        assert_eq!(receipt.public_values, vec![7u8; 4]);
+    }
+
+    #[test]
+    fn test_minimized_receipt_drops_sensitive_metadata() {
+        let engine = testing::MockEngine::builder()
+            .metadata(
+                serde_json::to_vec(&serde_json::json!({
+                    "proof_system": "sp1",
+                    "mode": "groth16",
+                    "vkey_hash": "0xabc",
+                    "prover_url": "https://internal-prover.local",
+                    "hostname": "build-07",
+                }))
+                .unwrap(),
+            )
+            .build();
+        let receipt = engine.prove(b"values").unwrap();
+
+        let minimized = receipt.minimized();
+        let metadata: serde_json::Value = serde_json::from_slice(&minimized.metadata).unwrap();
+
+        assert_eq!(metadata.as_object().unwrap().len(), 3);
+        assert_eq!(metadata["mode"], "groth16");
+        assert!(metadata.get("prover_url").is_none());
+        assert!(metadata.get("hostname").is_none());
+        assert!(engine.verify(&minimized).unwrap());
+        assert_eq!(minimized.public_values, receipt.public_values);
+    }
+
+    #[test]
+    fn test_verify_with_report_decodes_rwa_public_values() {
+        let engine = BatchTestEngine;
+        let claim = rwa::RwaClaim::new([7u8; 32], 1_000_000, 500_000, 1_735_128_100, [9u8; 16], [0u8; 64]);
+        let public_values = borsh::to_vec(&rwa::RwaPublicValues::from_claim(&claim, rwa::FreshnessWindow::default())).unwrap();
+        let receipt = engine.prove(&public_values).unwrap();
+
+        let report = engine.verify_with_report(&receipt).unwrap();
+
+        assert!(report.valid);
+        assert_eq!(report.public_values, public_values);
+        let decoded = report.decoded.expect("RWA public values should decode");
+        assert_eq!(decoded.institutional_pubkey, [7u8; 32]);
+        assert_eq!(decoded.threshold, 500_000);
+        assert_eq!(report.vkey_hash, [0u8; 32]);
+    }
+
+    #[test]
+    fn test_verify_with_report_non_rwa_public_values() {
+        let engine = BatchTestEngine;
+        let receipt = engine.prove(b"not rwa").unwrap();
+
+        let report = engine.verify_with_report(&receipt).unwrap();
+
+        assert!(report.valid);
+        assert!(report.decoded.is_none());
+    }
+
+    #[test]
+    fn test_verify_batch_mixed_results() {
+        let engine = BatchTestEngine;
+        let valid = engine.prove(b"claim").unwrap();
+        let mut corrupt = valid.clone();
+        corrupt.proof = vec![0xBB];
+        let mut empty = valid.clone();
+        empty.proof.clear();
+
+        let results = engine.verify_batch(&[valid.clone(), corrupt, valid, empty]);
+
+        assert_eq!(results.len(), 4);
+        assert!(results[0].as_ref().unwrap());
+        assert!(!results[1].as_ref().unwrap());
+        assert!(results[2].as_ref().unwrap());
+        assert!(matches!(results[3], Err(PrivacyEngineError::VerificationFailed(_))));
+    }
+
+    #[test]
+    fn test_verify_batch_strict() {
+        let engine = BatchTestEngine;
+        let valid = engine.prove(b"claim").unwrap();
+        let mut corrupt = valid.clone();
+        corrupt.proof = vec![0xBB];
+        let mut empty = valid.clone();
+        empty.proof.clear();
+
+        let results = engine
+            .verify_batch_strict(&[valid.clone(), corrupt.clone(), valid.clone()])
+            .unwrap();
+        assert_eq!(results, vec![true, false, true]);
+
+        let result = engine.verify_batch_strict(&[valid, empty, corrupt]);
+        assert!(matches!(result, Err(PrivacyEngineError::VerificationFailed(_))));
     }
 }
@@ -2,12 +2,14 @@
 //!
 //! Verifiable logging of all agent actions for compliance and debugging.
 
+use crate::ChainType;
+use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Agent action types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub enum AgentAction {
     ExtractClaim,
     GenerateProof,
@@ -17,7 +19,7 @@ pub enum AgentAction {
 }
 
 /// Single audit entry
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub struct AuditEntry {
     /// Unix timestamp
     pub timestamp: i64,
@@ -40,8 +42,27 @@ pub struct AuditEntry {
     /// Hash of previous entry (blockchain-like)
     pub previous_hash: [u8; 32],
     
-    /// Nonce for uniqueness
+    /// Monotonic position of this entry in the trail (starts at 0)
+    ///
+    /// Keeps entries added within the same second distinguishable and ordered.
+    pub sequence: u64,
+    
+    /// Nonce for uniqueness, derived from `previous_hash` and `sequence`
     pub nonce: u64,
+    
+    /// Chain and timing details, where the action has them
+    #[serde(default)]
+    pub context: AuditContext,
+}
+
+/// Optional chain and timing details attached to an audit entry
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct AuditContext {
+    /// Chain the action targeted (e.g. for `SubmitToChain`)
+    pub chain: Option<ChainType>,
+    
+    /// Wall-clock duration of the action in milliseconds
+    pub duration_ms: Option<u64>,
 }
 
 impl AuditEntry {
@@ -53,7 +74,7 @@ impl AuditEntry {
         decision_logic: &[u8],
         confidence: f32,
         previous_hash: [u8; 32],
-        nonce: u64,
+        sequence: u64,
     ) -> Self {
         Self {
             timestamp: SystemTime::now()
@@ -66,10 +87,26 @@ impl AuditEntry {
             decision_logic_hash: Self::hash(decision_logic),
             confidence,
             previous_hash,
-            nonce,
+            sequence,
+            nonce: Self::derive_nonce(&previous_hash, sequence),
+            context: AuditContext::default(),
         }
     }
 
+    /// Derive the nonce for an entry from its predecessor's hash and its sequence.
+    ///
+    /// Binding the nonce to the previous entry's full hash means it can't be
+    /// chosen freely to grind entry hashes.
+    pub fn derive_nonce(previous_hash: &[u8; 32], sequence: u64) -> u64 {
+        let mut hasher = Sha256::new();
+        hasher.update(previous_hash);
+        hasher.update(sequence.to_le_bytes());
+        let digest = hasher.finalize();
+        let mut nonce = [0u8; 8];
+        nonce.copy_from_slice(&digest[..8]);
+        u64::from_le_bytes(nonce)
+    }
+
     /// Hash data using SHA256
     fn hash(data: &[u8]) -> [u8; 32] {
         let mut hasher = Sha256::new();
@@ -84,8 +121,52 @@ impl AuditEntry {
     }
 }
 
+/// Where `ZkAuditTrail::verify_integrity_detailed` found the trail broken
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IntegrityError {
+    /// Entry `index`'s nonce isn't derived from its `previous_hash` and `sequence`
+    #[error("Audit entry {index} has a nonce that doesn't match its link and sequence")]
+    NonceMismatch { index: usize },
+
+    /// Entry `index`'s `previous_hash` doesn't match the hash of entry `index - 1`
+    #[error("Audit entry {index} does not link to the previous entry")]
+    BrokenLink { index: usize },
+
+    /// Entry `index`'s sequence isn't greater than its predecessor's
+    #[error("Audit entry {index} is out of sequence")]
+    SequenceOutOfOrder { index: usize },
+
+    /// The entries are linked correctly but don't hash to `trail_hash`
+    #[error("Audit trail hash does not match its entries")]
+    TrailHashMismatch,
+}
+
+/// Errors exporting a binary audit trail with `ZkAuditTrail::export_binary`
+#[derive(Debug, thiserror::Error)]
+pub enum AuditExportError {
+    /// An entry's confidence is NaN, which Borsh refuses to encode
+    #[error("Audit entry {sequence} has a NaN confidence score")]
+    NanConfidence { sequence: u64 },
+
+    /// Borsh encoding failed for another reason
+    #[error("Failed to encode audit trail: {0}")]
+    Encode(#[from] std::io::Error),
+}
+
+/// Errors importing a binary audit trail with `ZkAuditTrail::import_binary`
+#[derive(Debug, thiserror::Error)]
+pub enum AuditImportError {
+    /// The bytes are not a Borsh-encoded trail
+    #[error("Invalid audit trail encoding: {0}")]
+    Decode(#[from] std::io::Error),
+
+    /// The trail decoded but its hash chain or trail hash doesn't check out
+    #[error("Audit trail failed integrity verification: {0}")]
+    IntegrityCheckFailed(#[from] IntegrityError),
+}
+
 /// ZK Audit Trail - verifiable log of agent decisions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub struct ZkAuditTrail {
     /// All audit entries
     pub entries: Vec<AuditEntry>,
@@ -118,22 +199,45 @@ impl ZkAuditTrail {
         output: &[u8],
         decision_logic: &[u8],
         confidence: f32,
+    ) {
+        self.add_entry_with_context(
+            action,
+            input,
+            output,
+            decision_logic,
+            confidence,
+            AuditContext::default(),
+        );
+    }
+
+    /// Add an entry carrying chain and timing context
+    pub fn add_entry_with_context(
+        &mut self,
+        action: AgentAction,
+        input: &[u8],
+        output: &[u8],
+        decision_logic: &[u8],
+        confidence: f32,
+        context: AuditContext,
     ) {
         let previous_hash = self.entries.last()
             .map(|e| e.compute_hash())
             .unwrap_or([0u8; 32]);
 
-        let nonce = self.entries.len() as u64;
+        let sequence = self.entries.last()
+            .map(|e| e.sequence + 1)
+            .unwrap_or(0);
 
-        let entry = AuditEntry::new(
+        let mut entry = AuditEntry::new(
             action,
             input,
             output,
             decision_logic,
             confidence,
             previous_hash,
-            nonce,
+            sequence,
         );
+        entry.context = context;
 
         self.entries.push(entry);
         self.update_trail_hash();
@@ -149,11 +253,30 @@ impl ZkAuditTrail {
 
     /// Verify trail integrity
     pub fn verify_integrity(&self) -> bool {
-        // Check each entry's previous_hash matches the previous entry's hash
-        for i in 1..self.entries.len() {
-            let prev_hash = self.entries[i - 1].compute_hash();
-            if self.entries[i].previous_hash != prev_hash {
-                return false;
+        self.verify_integrity_detailed().is_ok()
+    }
+
+    /// Verify trail integrity, reporting the first problem found
+    ///
+    /// Entries are checked in order, so the reported index is the earliest
+    /// point where the chain breaks. Tampering with entry `i` shows up as a
+    /// `BrokenLink` at `i + 1`, or as `TrailHashMismatch` for the last entry.
+    pub fn verify_integrity_detailed(&self) -> Result<(), IntegrityError> {
+        for (index, entry) in self.entries.iter().enumerate() {
+            // Each entry's nonce is bound to its predecessor and sequence
+            if entry.nonce != AuditEntry::derive_nonce(&entry.previous_hash, entry.sequence) {
+                return Err(IntegrityError::NonceMismatch { index });
+            }
+
+            // previous_hash matches the previous entry's hash and sequence
+            // numbers are strictly increasing
+            if let Some(previous) = index.checked_sub(1).map(|i| &self.entries[i]) {
+                if entry.previous_hash != previous.compute_hash() {
+                    return Err(IntegrityError::BrokenLink { index });
+                }
+                if entry.sequence <= previous.sequence {
+                    return Err(IntegrityError::SequenceOutOfOrder { index });
+                }
             }
         }
 
@@ -163,7 +286,10 @@ impl ZkAuditTrail {
         hasher.update(&serialized);
         let computed_hash: [u8; 32] = hasher.finalize().into();
 
-        computed_hash == self.trail_hash
+        if computed_hash != self.trail_hash {
+            return Err(IntegrityError::TrailHashMismatch);
+        }
+        Ok(())
     }
 
     /// Export trail as JSON
@@ -171,6 +297,67 @@ impl ZkAuditTrail {
         serde_json::to_string_pretty(self)
     }
 
+    /// 32-byte commitment to the whole trail, suitable for anchoring on-chain
+    ///
+    /// This is `trail_hash`. Publish it, then later hand over `export_binary`
+    /// and check it with `verify_against_commitment`.
+    pub fn export_commitment(&self) -> [u8; 32] {
+        self.trail_hash
+    }
+
+    /// Export the trail in a compact, deterministic Borsh encoding
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditExportError::NanConfidence` if an entry's confidence is
+    /// NaN, which Borsh refuses to encode
+    pub fn export_binary(&self) -> Result<Vec<u8>, AuditExportError> {
+        if let Some(entry) = self.entries.iter().find(|e| e.confidence.is_nan()) {
+            return Err(AuditExportError::NanConfidence { sequence: entry.sequence });
+        }
+        Ok(borsh::to_vec(self)?)
+    }
+
+    /// Decode a trail written by `export_binary` and verify its integrity
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditImportError::Decode` for malformed bytes and
+    /// `AuditImportError::IntegrityCheckFailed` if the decoded trail doesn't
+    /// pass `verify_integrity`
+    pub fn import_binary(bytes: &[u8]) -> Result<ZkAuditTrail, AuditImportError> {
+        let trail: ZkAuditTrail = borsh::from_slice(bytes)?;
+        trail.verify_integrity_detailed()?;
+        Ok(trail)
+    }
+
+    /// Check the trail is intact and matches a previously published commitment
+    pub fn verify_against_commitment(&self, root: [u8; 32]) -> bool {
+        self.trail_hash == root && self.verify_integrity()
+    }
+
+    /// Entries recording `action`, in trail order
+    pub fn entries_by_action(&self, action: &AgentAction) -> Vec<&AuditEntry> {
+        self.entries.iter().filter(|e| &e.action == action).collect()
+    }
+
+    /// Entries with `start_ts <= timestamp <= end_ts`, in trail order
+    pub fn entries_in_range(&self, start_ts: i64, end_ts: i64) -> Vec<&AuditEntry> {
+        self.entries
+            .iter()
+            .filter(|e| (start_ts..=end_ts).contains(&e.timestamp))
+            .collect()
+    }
+
+    /// Up to `limit` entries starting at `offset`
+    ///
+    /// Returns an empty slice once `offset` is past the end.
+    pub fn page(&self, offset: usize, limit: usize) -> &[AuditEntry] {
+        let start = offset.min(self.entries.len());
+        let end = start.saturating_add(limit).min(self.entries.len());
+        &self.entries[start..end]
+    }
+
     /// Get total entries
     pub fn len(&self) -> usize {
         self.entries.len()
@@ -265,6 +452,41 @@ mod tests {
         assert!(!trail.verify_integrity());
     }
 
+    #[test]
+    fn test_tight_loop_ordering() {
+        let mut trail = ZkAuditTrail::new();
+
+        // All of these land within the same second
+        for i in 0..100u32 {
+            trail.add_entry(
+                AgentAction::VerifyProof,
+                &i.to_le_bytes(),
+                b"same output",
+                b"same logic",
+                1.0,
+            );
+        }
+
+        assert!(trail.verify_integrity());
+        for (i, entry) in trail.entries.iter().enumerate() {
+            assert_eq!(entry.sequence, i as u64);
+        }
+        let mut nonces: Vec<u64> = trail.entries.iter().map(|e| e.nonce).collect();
+        nonces.dedup();
+        assert_eq!(nonces.len(), 100);
+    }
+
+    #[test]
+    fn test_reordered_sequence_fails() {
+        let mut trail = ZkAuditTrail::new();
+        trail.add_entry(AgentAction::ExtractClaim, b"a", b"b", b"c", 0.9);
+        trail.add_entry(AgentAction::GenerateProof, b"d", b"e", b"f", 0.9);
+
+        // Tampering with the sequence breaks the nonce binding
+        trail.entries[1].sequence = 0;
+        assert!(!trail.verify_integrity());
+    }
+
     #[test]
     fn test_export_json() {
         let mut trail = ZkAuditTrail::new();
@@ -279,4 +501,143 @@ mod tests {
         let json = trail.export_json().unwrap();
         assert!(json.contains("ExtractClaim"));
     }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let mut trail = ZkAuditTrail::new();
+        trail.add_entry(AgentAction::ExtractClaim, b"input", b"output", b"logic", 0.9);
+        trail.add_entry_with_context(
+            AgentAction::SubmitToChain,
+            b"proof",
+            b"tx",
+            b"submit",
+            1.0,
+            AuditContext { chain: Some(ChainType::Cosmwasm), duration_ms: Some(42) },
+        );
+
+        let root = trail.export_commitment();
+        let bytes = trail.export_binary().unwrap();
+        assert_eq!(bytes, trail.export_binary().unwrap());
+        assert!(bytes.len() < trail.export_json().unwrap().len());
+
+        let imported = ZkAuditTrail::import_binary(&bytes).unwrap();
+        assert_eq!(imported.len(), 2);
+        assert_eq!(imported.created_at, trail.created_at);
+        assert_eq!(imported.entries[1].context, trail.entries[1].context);
+        assert!(imported.verify_against_commitment(root));
+        assert!(!imported.verify_against_commitment([0u8; 32]));
+    }
+
+    #[test]
+    fn test_tampered_binary_rejected() {
+        let mut trail = ZkAuditTrail::new();
+        trail.add_entry(AgentAction::GenerateProof, b"input", b"output", b"logic", 0.9);
+        let root = trail.export_commitment();
+
+        let mut tampered = trail.clone();
+        tampered.entries[0].confidence = 0.1;
+        assert!(!tampered.verify_against_commitment(root));
+        assert!(matches!(
+            ZkAuditTrail::import_binary(&tampered.export_binary().unwrap()),
+            Err(AuditImportError::IntegrityCheckFailed(_))
+        ));
+
+        let bytes = trail.export_binary().unwrap();
+        assert!(matches!(
+            ZkAuditTrail::import_binary(&bytes[..bytes.len() - 1]),
+            Err(AuditImportError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn test_nan_confidence_export_fails() {
+        let mut trail = ZkAuditTrail::new();
+        trail.add_entry(AgentAction::GenerateProof, b"input", b"output", b"logic", 0.9);
+        trail.add_entry(AgentAction::GenerateProof, b"input", b"output", b"logic", f32::NAN);
+
+        assert!(matches!(
+            trail.export_binary(),
+            Err(AuditExportError::NanConfidence { sequence: 1 })
+        ));
+    }
+
+    /// Trail with entries at fixed timestamps (the hash chain is not rebuilt)
+    fn trail_at(times_and_actions: &[(i64, AgentAction)]) -> ZkAuditTrail {
+        let mut trail = ZkAuditTrail::new();
+        for (timestamp, action) in times_and_actions {
+            trail.add_entry(action.clone(), b"in", b"out", b"logic", 1.0);
+            trail.entries.last_mut().unwrap().timestamp = *timestamp;
+        }
+        trail
+    }
+
+    #[test]
+    fn test_entries_by_action() {
+        let trail = trail_at(&[
+            (100, AgentAction::GenerateProof),
+            (200, AgentAction::SubmitToChain),
+            (300, AgentAction::VerifyProof),
+            (400, AgentAction::SubmitToChain),
+        ]);
+
+        let submissions = trail.entries_by_action(&AgentAction::SubmitToChain);
+        assert_eq!(submissions.iter().map(|e| e.sequence).collect::<Vec<_>>(), vec![1, 3]);
+        assert!(trail.entries_by_action(&AgentAction::ExportVerifier).is_empty());
+    }
+
+    #[test]
+    fn test_entries_in_range_includes_boundaries() {
+        let trail = trail_at(&[
+            (100, AgentAction::SubmitToChain),
+            (200, AgentAction::SubmitToChain),
+            (300, AgentAction::SubmitToChain),
+            (400, AgentAction::SubmitToChain),
+        ]);
+
+        let timestamps = |entries: Vec<&AuditEntry>| entries.iter().map(|e| e.timestamp).collect::<Vec<_>>();
+        assert_eq!(timestamps(trail.entries_in_range(200, 300)), vec![200, 300]);
+        assert_eq!(timestamps(trail.entries_in_range(101, 399)), vec![200, 300]);
+        assert_eq!(timestamps(trail.entries_in_range(400, 400)), vec![400]);
+        assert!(trail.entries_in_range(301, 399).is_empty());
+        assert!(trail.entries_in_range(300, 200).is_empty());
+    }
+
+    #[test]
+    fn test_page() {
+        let trail = trail_at(&[
+            (1, AgentAction::ExtractClaim),
+            (2, AgentAction::GenerateProof),
+            (3, AgentAction::VerifyProof),
+        ]);
+
+        assert_eq!(trail.page(0, 2).len(), 2);
+        assert_eq!(trail.page(2, 2)[0].timestamp, 3);
+        assert_eq!(trail.page(2, 2).len(), 1);
+        assert!(trail.page(3, 2).is_empty());
+        assert!(trail.page(10, usize::MAX).is_empty());
+        assert_eq!(trail.page(1, usize::MAX).len(), 2);
+    }
+
+    #[test]
+    fn test_integrity_error_locates_tampering() {
+        let mut trail = ZkAuditTrail::new();
+        for i in 0..5u8 {
+            trail.add_entry(AgentAction::VerifyProof, &[i], b"out", b"logic", 1.0);
+        }
+        assert_eq!(trail.verify_integrity_detailed(), Ok(()));
+
+        let mut tampered = trail.clone();
+        tampered.entries[2].output_hash = [0xEE; 32];
+        assert_eq!(tampered.verify_integrity_detailed(), Err(IntegrityError::BrokenLink { index: 3 }));
+        assert!(!tampered.verify_integrity());
+
+        // The last entry has no successor, so only the trail hash catches it
+        let mut tampered = trail.clone();
+        tampered.entries[4].confidence = 0.0;
+        assert_eq!(tampered.verify_integrity_detailed(), Err(IntegrityError::TrailHashMismatch));
+
+        let mut tampered = trail;
+        tampered.entries[1].sequence = 7;
+        assert_eq!(tampered.verify_integrity_detailed(), Err(IntegrityError::NonceMismatch { index: 1 }));
+    }
 }
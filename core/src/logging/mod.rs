@@ -4,4 +4,4 @@
 
 pub mod audit;
 
-pub use audit::{ZkAuditTrail, AuditEntry, AgentAction};
+pub use audit::{ZkAuditTrail, AuditEntry, AuditContext, AgentAction, AuditExportError, AuditImportError, IntegrityError};
@@ -15,13 +15,14 @@ use serde_json::json;
 use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
 use universal_privacy_engine_core::notary::{
-    GenerateProofRequest, HealthResponse, NotarySigner, STLOPProof,
+    salary_provider_from_env_vars, GenerateProofRequest, HealthResponse, NotaryError,
+    NotaryRegistry, STLOPProof,
 };
 
 /// Shared application state
 #[derive(Clone)]
 struct AppState {
-    notary: Arc<NotarySigner>,
+    notaries: Arc<NotaryRegistry>,
 }
 
 #[tokio::main]
@@ -32,21 +33,24 @@ async fn main() {
     // Initialize logging
     tracing_subscriber::fmt::init();
 
-    // Load notary private key from environment
-    let notary_private_key = std::env::var("NOTARY_PRIVATE_KEY")
-        .expect("NOTARY_PRIVATE_KEY must be set in .env file");
+    // Salary source (NOTARY_SALARY_SOURCE, default: static simulated salary)
+    let salaries = salary_provider_from_env_vars(std::env::vars())
+        .expect("Failed to configure salary source");
 
-    // Create notary signer
-    let notary = NotarySigner::new(&notary_private_key)
-        .expect("Failed to create notary signer");
+    // Load notary keys (NOTARY_PRIVATE_KEY and/or NOTARY_PRIVATE_KEY_<ID>)
+    let notaries = NotaryRegistry::from_env_vars(std::env::vars())
+        .expect("Failed to load notary keys")
+        .with_salary_provider(salaries);
 
-    let notary_address = format!("0x{:x}", notary.address());
     println!("🔐 Notary Service Starting...");
-    println!("📍 Notary Address: {}", notary_address);
+    for (id, address) in notaries.addresses() {
+        let marker = if id == notaries.primary_id() { " (primary)" } else { "" };
+        println!("📍 Notary [{}]: {}{}", id, address, marker);
+    }
 
     // Create shared state
     let state = AppState {
-        notary: Arc::new(notary),
+        notaries: Arc::new(notaries),
     };
 
     // Configure CORS to allow all origins and headers.
@@ -77,7 +81,7 @@ async fn main() {
     println!("   GET  /api/health");
     println!("   GET  /healthz         (liveness probe)");
     println!("   POST /api/generate-proof");
-    println!("");
+    println!();
 
     // Start server
     let listener = tokio::net::TcpListener::bind(&addr)
@@ -95,14 +99,15 @@ async fn main() {
 async fn health_check(State(state): State<AppState>) -> Json<HealthResponse> {
     Json(HealthResponse {
         status: "ok".to_string(),
-        notary_address: format!("0x{:x}", state.notary.address()),
+        notary_address: format!("0x{:x}", state.notaries.primary().address()),
+        notary_addresses: state.notaries.addresses(),
     })
 }
 
 /// Generate STLOP proof endpoint
 ///
 /// POST /api/generate-proof
-/// Body: { "employee_address": "0x..." }
+/// Body: { "employee_address": "0x...", "notary_id": "backup" (optional) }
 async fn generate_proof(
     State(state): State<AppState>,
     Json(request): Json<GenerateProofRequest>,
@@ -117,8 +122,25 @@ async fn generate_proof(
         ));
     }
 
+    // Pick the requested notary (primary if none given)
+    let notary = match state.notaries.get(request.notary_id.as_deref()) {
+        Ok(notary) => notary,
+        Err(e @ NotaryError::UnknownNotary(_)) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": e.to_string() })),
+            ));
+        }
+        Err(e) => {
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": e.to_string() })),
+            ));
+        }
+    };
+
     // Generate proof
-    match state.notary.generate_proof(&request.employee_address).await {
+    match notary.generate_proof(&request.employee_address).await {
         Ok(proof) => {
             println!("✅ Generated proof for employee: {}", request.employee_address);
             Ok(Json(proof))
@@ -10,17 +10,21 @@
 //!   - address  → 20 bytes (no zero-pad on the left in packed encoding)
 //!   - uint256  → 32 bytes big-endian
 //!   - uint256  → 32 bytes big-endian
+//!
 //! Total: 84 bytes, then keccak256'd.
 //!
 //! EIP-191 prefix is applied by `ethers_signers::Signer::sign_message`, which
 //! computes `keccak256("\x19Ethereum Signed Message:\n32" || messageHash)`.
 //! This matches `ECDSA.toEthSignedMessageHash(messageHash)` in OZ Solidity.
 
+use async_trait::async_trait;
 use ethers_core::types::{Address, Signature, H256, U256};
 use ethers_signers::{LocalWallet, Signer};
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Keccak256};
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
+use std::sync::Arc;
 use thiserror::Error;
 
 // ── Public types ──────────────────────────────────────────────────────────────
@@ -28,7 +32,7 @@ use thiserror::Error;
 /// A notary-signed proof of salary data, verifiable on-chain via `ecrecover`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct STLOPProof {
-    /// Salary amount in USD cents (stored as string to avoid JS number precision loss)
+    /// Salary amount in whole USD (stored as string to avoid JS number precision loss)
     pub salary: String,
     /// Unix timestamp (seconds) when this proof was generated
     pub timestamp: u64,
@@ -42,13 +46,19 @@ pub struct STLOPProof {
 #[derive(Debug, Deserialize)]
 pub struct GenerateProofRequest {
     pub employee_address: String,
+    /// Which notary key should sign; defaults to the primary notary
+    #[serde(default)]
+    pub notary_id: Option<String>,
 }
 
 /// Response body for `GET /api/health` and `GET /healthz`
 #[derive(Debug, Serialize)]
 pub struct HealthResponse {
     pub status: String,
+    /// Address of the primary notary
     pub notary_address: String,
+    /// Addresses of every loaded notary, keyed by notary id
+    pub notary_addresses: BTreeMap<String, String>,
 }
 
 // ── Errors ────────────────────────────────────────────────────────────────────
@@ -61,20 +71,110 @@ pub enum NotaryError {
     SigningFailed(String),
     #[error("invalid address: {0}")]
     InvalidAddress(String),
+    #[error("unknown notary id: {0}")]
+    UnknownNotary(String),
+    #[error("no notary keys configured (set NOTARY_PRIVATE_KEY or NOTARY_PRIVATE_KEY_<ID>)")]
+    NoNotaryKeys,
+    #[error("malformed proof: {0}")]
+    InvalidProof(String),
+    #[error("salary lookup failed: {0}")]
+    SalaryLookupFailed(String),
+    #[error("invalid salary source config: {0}")]
+    InvalidSalaryConfig(String),
+}
+
+// ── Salary sources ────────────────────────────────────────────────────────────
+
+/// Where a notary gets the salary it attests to.
+///
+/// The static provider stands in until a TLSNotary-backed provider can read
+/// salaries from a verified payroll session.
+#[async_trait]
+pub trait SalaryProvider: Send + Sync {
+    /// Salary for `employee`, in whole USD (e.g. `75_000` for $75,000).
+    async fn lookup(&self, employee: &Address) -> Result<u64, NotaryError>;
+}
+
+/// Salary reported when no source is configured, in whole USD (simulated, see `generate_proof`).
+pub const DEFAULT_SIMULATED_SALARY: u64 = 75_000;
+
+/// Env var choosing the salary source; only `static` is supported today.
+pub const SALARY_SOURCE_ENV: &str = "NOTARY_SALARY_SOURCE";
+
+/// Env var holding the salary returned by the `static` source.
+pub const STATIC_SALARY_ENV: &str = "NOTARY_STATIC_SALARY";
+
+/// Returns the same salary for every employee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaticSalaryProvider(pub u64);
+
+#[async_trait]
+impl SalaryProvider for StaticSalaryProvider {
+    async fn lookup(&self, _employee: &Address) -> Result<u64, NotaryError> {
+        Ok(self.0)
+    }
+}
+
+/// Build the salary provider selected by environment-style `(name, value)` pairs.
+///
+/// `NOTARY_SALARY_SOURCE` defaults to `static`, which returns
+/// `NOTARY_STATIC_SALARY` (default [`DEFAULT_SIMULATED_SALARY`]) for everyone.
+pub fn salary_provider_from_env_vars<I>(vars: I) -> Result<Arc<dyn SalaryProvider>, NotaryError>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let mut source = None;
+    let mut static_salary = None;
+    for (name, value) in vars {
+        if name == SALARY_SOURCE_ENV {
+            source = Some(value);
+        } else if name == STATIC_SALARY_ENV {
+            static_salary = Some(value);
+        }
+    }
+
+    match source.as_deref().map(str::to_lowercase).as_deref() {
+        None | Some("static") => {
+            let salary = match static_salary {
+                Some(value) => value.parse().map_err(|e| {
+                    NotaryError::InvalidSalaryConfig(format!("{}={}: {}", STATIC_SALARY_ENV, value, e))
+                })?,
+                None => DEFAULT_SIMULATED_SALARY,
+            };
+            Ok(Arc::new(StaticSalaryProvider(salary)))
+        }
+        Some(other) => Err(NotaryError::InvalidSalaryConfig(format!(
+            "unsupported {}: {}",
+            SALARY_SOURCE_ENV, other
+        ))),
+    }
 }
 
 // ── Core signer ───────────────────────────────────────────────────────────────
 
 pub struct NotarySigner {
     wallet: LocalWallet,
+    salaries: Arc<dyn SalaryProvider>,
 }
 
 impl NotarySigner {
     /// Load a signer from a hex-encoded secp256k1 private key (with or without 0x prefix).
+    ///
+    /// Salaries come from `StaticSalaryProvider(DEFAULT_SIMULATED_SALARY)`
+    /// until replaced with [`NotarySigner::with_salary_provider`].
     pub fn new(private_key_hex: &str) -> Result<Self, NotaryError> {
         let wallet = LocalWallet::from_str(private_key_hex)
             .map_err(|e| NotaryError::InvalidPrivateKey(e.to_string()))?;
-        Ok(Self { wallet })
+        Ok(Self {
+            wallet,
+            salaries: Arc::new(StaticSalaryProvider(DEFAULT_SIMULATED_SALARY)),
+        })
+    }
+
+    /// Use `provider` as the salary source for proofs from this signer.
+    pub fn with_salary_provider(mut self, provider: Arc<dyn SalaryProvider>) -> Self {
+        self.salaries = provider;
+        self
     }
 
     /// Returns the Ethereum address derived from the notary's public key.
@@ -102,23 +202,24 @@ impl NotarySigner {
         // timestamp uint256: 32 bytes big-endian
         timestamp.to_big_endian(&mut buf[52..84]);
 
-        let hash = Keccak256::digest(&buf);
+        let hash = Keccak256::digest(buf);
         H256::from_slice(&hash)
     }
 
     /// Generate a STLOP proof for `employee_address`.
     ///
-    /// Currently the salary is simulated ($75,000). Phase 2 will replace
-    /// this block with a TLSNotary MPC proof — see docs/ARCHITECTURE.md.
+    /// The salary comes from this signer's `SalaryProvider`, which is
+    /// simulated by default. Phase 2 adds a TLSNotary MPC-backed provider —
+    /// see docs/ARCHITECTURE.md.
     pub async fn generate_proof(&self, employee_address: &str) -> Result<STLOPProof, NotaryError> {
         let employee = Address::from_str(employee_address)
             .map_err(|e| NotaryError::InvalidAddress(e.to_string()))?;
 
-        // TODO(phase-2): replace this with a real TLSNotary MPC proof.
+        // TODO(phase-2): back this with a real TLSNotary MPC proof.
         // Flow: browser opens TLS session with payroll provider → generates
         // local transcript proof → notary verifies proof via MPC handshake
         // → signs only after proof checks out. See docs/ARCHITECTURE.md.
-        let salary_raw: u64 = 75_000;
+        let salary_raw = self.salaries.lookup(&employee).await?;
         let salary = U256::from(salary_raw);
 
         let timestamp_secs = std::time::SystemTime::now()
@@ -148,6 +249,135 @@ impl NotarySigner {
     }
 }
 
+// ── Verification ──────────────────────────────────────────────────────────────
+
+/// Check a STLOP proof client-side, mirroring the verifier contract's `ecrecover`.
+///
+/// Rebuilds `keccak256(abi.encodePacked(employee, salary, timestamp))`, applies
+/// the EIP-191 prefix, recovers the signer and compares it to
+/// `proof.notary_pubkey`.
+///
+/// Returns `Ok(false)` for a well-formed proof signed by someone else or over
+/// different data (e.g. tampered salary or timestamp).
+///
+/// # Errors
+///
+/// Returns `NotaryError::InvalidAddress` if `employee` or `proof.notary_pubkey`
+/// isn't an address, and `NotaryError::InvalidProof` if the salary or
+/// signature can't be decoded
+pub fn verify_stlop(proof: &STLOPProof, employee: &str) -> Result<bool, NotaryError> {
+    let employee = Address::from_str(employee)
+        .map_err(|e| NotaryError::InvalidAddress(e.to_string()))?;
+    let notary = Address::from_str(&proof.notary_pubkey)
+        .map_err(|e| NotaryError::InvalidAddress(e.to_string()))?;
+    let salary = U256::from_dec_str(&proof.salary)
+        .map_err(|e| NotaryError::InvalidProof(format!("salary: {}", e)))?;
+    let signature = Signature::from_str(&proof.signature)
+        .map_err(|e| NotaryError::InvalidProof(format!("signature: {}", e)))?;
+
+    let message_hash = NotarySigner::create_message_hash(employee, salary, U256::from(proof.timestamp));
+    let eth_signed = ethers_core::utils::hash_message(message_hash.as_bytes());
+    let recovered = signature
+        .recover(eth_signed)
+        .map_err(|e| NotaryError::InvalidProof(format!("signature: {}", e)))?;
+
+    Ok(recovered == notary)
+}
+
+// ── Multi-key registry ────────────────────────────────────────────────────────
+
+/// Id given to the key loaded from the un-suffixed `NOTARY_PRIVATE_KEY`.
+pub const PRIMARY_NOTARY_ID: &str = "primary";
+
+/// Env var prefix for notary keys: `NOTARY_PRIVATE_KEY` or `NOTARY_PRIVATE_KEY_<ID>`.
+pub const NOTARY_KEY_ENV_PREFIX: &str = "NOTARY_PRIVATE_KEY";
+
+/// Env var selecting which loaded notary id is the primary.
+pub const NOTARY_PRIMARY_ID_ENV: &str = "NOTARY_PRIMARY_ID";
+
+/// A set of notary signers keyed by id, enabling key rotation and sharding.
+pub struct NotaryRegistry {
+    signers: HashMap<String, NotarySigner>,
+    primary: String,
+}
+
+impl NotaryRegistry {
+    /// Build a registry from environment-style `(name, value)` pairs.
+    ///
+    /// `NOTARY_PRIVATE_KEY` is loaded under [`PRIMARY_NOTARY_ID`] and
+    /// `NOTARY_PRIVATE_KEY_<ID>` under the lowercased `<ID>`. The primary is
+    /// `NOTARY_PRIMARY_ID` if set, otherwise `primary`, otherwise the
+    /// alphabetically first id.
+    pub fn from_env_vars<I>(vars: I) -> Result<Self, NotaryError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut signers = HashMap::new();
+        let mut primary_override = None;
+
+        for (name, value) in vars {
+            if name == NOTARY_PRIMARY_ID_ENV {
+                primary_override = Some(value.to_lowercase());
+                continue;
+            }
+            let id = match name.strip_prefix(NOTARY_KEY_ENV_PREFIX) {
+                Some("") => PRIMARY_NOTARY_ID.to_string(),
+                Some(suffix) => match suffix.strip_prefix('_') {
+                    Some(id) if !id.is_empty() => id.to_lowercase(),
+                    _ => continue,
+                },
+                None => continue,
+            };
+            signers.insert(id, NotarySigner::new(&value)?);
+        }
+
+        let primary = match primary_override {
+            Some(id) if signers.contains_key(&id) => id,
+            Some(id) => return Err(NotaryError::UnknownNotary(id)),
+            None if signers.contains_key(PRIMARY_NOTARY_ID) => PRIMARY_NOTARY_ID.to_string(),
+            None => signers.keys().min().cloned().ok_or(NotaryError::NoNotaryKeys)?,
+        };
+
+        Ok(Self { signers, primary })
+    }
+
+    /// Look up a signer by id, falling back to the primary when `id` is `None`.
+    pub fn get(&self, id: Option<&str>) -> Result<&NotarySigner, NotaryError> {
+        let id = id.unwrap_or(&self.primary);
+        self.signers
+            .get(&id.to_lowercase())
+            .ok_or_else(|| NotaryError::UnknownNotary(id.to_string()))
+    }
+
+    /// The primary signer.
+    pub fn primary(&self) -> &NotarySigner {
+        &self.signers[&self.primary]
+    }
+
+    /// Id of the primary signer.
+    pub fn primary_id(&self) -> &str {
+        &self.primary
+    }
+
+    /// Use `provider` as the salary source for every loaded notary.
+    pub fn with_salary_provider(mut self, provider: Arc<dyn SalaryProvider>) -> Self {
+        self.signers = self
+            .signers
+            .into_iter()
+            .map(|(id, signer)| (id, signer.with_salary_provider(provider.clone())))
+            .collect();
+        self
+    }
+
+    /// EIP-55 checksummed-hex addresses of every loaded notary, keyed by id.
+    pub fn addresses(&self) -> BTreeMap<String, String> {
+        self.signers
+            .iter()
+            .map(|(id, signer)| (id.clone(), ethers_core::utils::to_checksum(&signer.address(), None)))
+            .collect()
+    }
+}
+
 // ── Tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -256,6 +486,152 @@ mod tests {
         );
     }
 
+    const EMPLOYEE: &str = "0x06deedD21AfE4ae6BFb443A4f560aD13d81e05a7";
+
+    #[tokio::test]
+    async fn test_verify_stlop_valid() {
+        let signer = NotarySigner::new(TEST_KEY).unwrap();
+        let proof = signer.generate_proof(EMPLOYEE).await.unwrap();
+
+        assert!(verify_stlop(&proof, EMPLOYEE).unwrap());
+        // Lowercase addresses are accepted too
+        assert!(verify_stlop(&proof, &EMPLOYEE.to_lowercase()).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_verify_stlop_tampered() {
+        let signer = NotarySigner::new(TEST_KEY).unwrap();
+        let proof = signer.generate_proof(EMPLOYEE).await.unwrap();
+
+        let mut salary = proof.clone();
+        salary.salary = "150000".to_string();
+        assert!(!verify_stlop(&salary, EMPLOYEE).unwrap());
+
+        let mut timestamp = proof.clone();
+        timestamp.timestamp += 1;
+        assert!(!verify_stlop(&timestamp, EMPLOYEE).unwrap());
+
+        // Proof issued for a different employee
+        assert!(!verify_stlop(&proof, "0x0000000000000000000000000000000000000001").unwrap());
+
+        let mut notary = proof.clone();
+        notary.notary_pubkey = format!("{:#x}", NotarySigner::new(SECOND_KEY).unwrap().address());
+        assert!(!verify_stlop(&notary, EMPLOYEE).unwrap());
+
+        let mut garbled = proof;
+        garbled.salary = "lots".to_string();
+        assert!(matches!(verify_stlop(&garbled, EMPLOYEE), Err(NotaryError::InvalidProof(_))));
+    }
+
+    /// Salary source recording which employee it was asked about
+    struct MockSalaries(std::sync::Mutex<Vec<Address>>);
+
+    #[async_trait]
+    impl SalaryProvider for MockSalaries {
+        async fn lookup(&self, employee: &Address) -> Result<u64, NotaryError> {
+            self.0.lock().unwrap().push(*employee);
+            Ok(123_456)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_salary_provider_injected() {
+        let provider = Arc::new(MockSalaries(Default::default()));
+        let signer = NotarySigner::new(TEST_KEY)
+            .unwrap()
+            .with_salary_provider(provider.clone());
+
+        let proof = signer.generate_proof(EMPLOYEE).await.unwrap();
+
+        assert_eq!(proof.salary, "123456");
+        assert!(verify_stlop(&proof, EMPLOYEE).unwrap());
+        assert_eq!(*provider.0.lock().unwrap(), vec![EMPLOYEE.parse::<Address>().unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_salary_provider_from_env() {
+        let employee: Address = EMPLOYEE.parse().unwrap();
+
+        let default = salary_provider_from_env_vars(env(&[])).unwrap();
+        assert_eq!(default.lookup(&employee).await.unwrap(), DEFAULT_SIMULATED_SALARY);
+
+        let configured = salary_provider_from_env_vars(env(&[
+            (SALARY_SOURCE_ENV, "static"),
+            (STATIC_SALARY_ENV, "90000"),
+        ]))
+        .unwrap();
+        assert_eq!(configured.lookup(&employee).await.unwrap(), 90_000);
+
+        for bad in [
+            env(&[(SALARY_SOURCE_ENV, "tlsnotary")]),
+            env(&[(STATIC_SALARY_ENV, "lots")]),
+        ] {
+            assert!(matches!(
+                salary_provider_from_env_vars(bad),
+                Err(NotaryError::InvalidSalaryConfig(_))
+            ));
+        }
+    }
+
+    const SECOND_KEY: &str =
+        "0x1111111111111111111111111111111111111111111111111111111111111111";
+
+    fn env(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[tokio::test]
+    async fn test_registry_routes_by_notary_id() {
+        let registry = NotaryRegistry::from_env_vars(env(&[
+            ("NOTARY_PRIVATE_KEY", TEST_KEY),
+            ("NOTARY_PRIVATE_KEY_BACKUP", SECOND_KEY),
+            ("UNRELATED", "x"),
+        ]))
+        .unwrap();
+
+        let backup_address = format!("{:#x}", NotarySigner::new(SECOND_KEY).unwrap().address());
+        let proof = registry
+            .get(Some("backup"))
+            .unwrap()
+            .generate_proof("0x06deedD21AfE4ae6BFb443A4f560aD13d81e05a7")
+            .await
+            .unwrap();
+        assert_eq!(proof.notary_pubkey, backup_address);
+
+        // No id → primary
+        assert_eq!(registry.primary_id(), PRIMARY_NOTARY_ID);
+        assert_eq!(
+            registry.get(None).unwrap().address(),
+            NotarySigner::new(TEST_KEY).unwrap().address()
+        );
+        let addresses = registry.addresses();
+        assert_eq!(addresses.len(), 2);
+        // Mixed-case EIP-55 checksum, not lowercase hex
+        assert_eq!(
+            addresses["backup"],
+            ethers_core::utils::to_checksum(&NotarySigner::new(SECOND_KEY).unwrap().address(), None)
+        );
+        assert_ne!(addresses["backup"], backup_address);
+        assert!(addresses["backup"].eq_ignore_ascii_case(&backup_address));
+        assert!(matches!(registry.get(Some("missing")), Err(NotaryError::UnknownNotary(_))));
+    }
+
+    #[test]
+    fn test_registry_primary_selection() {
+        let registry = NotaryRegistry::from_env_vars(env(&[
+            ("NOTARY_PRIVATE_KEY_A", TEST_KEY),
+            ("NOTARY_PRIVATE_KEY_B", SECOND_KEY),
+            ("NOTARY_PRIMARY_ID", "B"),
+        ]))
+        .unwrap();
+        assert_eq!(registry.primary_id(), "b");
+
+        assert!(matches!(
+            NotaryRegistry::from_env_vars(env(&[])),
+            Err(NotaryError::NoNotaryKeys)
+        ));
+    }
+
     #[tokio::test]
     async fn test_proof_output_format() {
         let signer = NotarySigner::new(TEST_KEY).unwrap();
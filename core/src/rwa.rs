@@ -9,8 +9,12 @@
 //! 2. **Borsh Serialization**: Deterministic encoding for ZK proofs
 //! 3. **Fixed-Size Types**: Prefer arrays over Vec for predictable memory usage
 //! 4. **Cryptographic Primitives**: Ed25519 for institutional signatures
+//! 5. **Parsing Never Panics**: Decoders for attacker-controlled bytes
+//!    (`from_wire`, `try_from_bytes`, `from_input_bytes`) return `Err` on any
+//!    malformed input; this is exercised by property tests and `core/fuzz`
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -19,6 +23,95 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "std")]
 use serde_big_array::BigArray;
 
+/// Version of the `RwaClaim` input layout shared by host and guest.
+///
+/// Bump this whenever the Borsh layout of `RwaClaim` changes, so a guest built
+/// against an older layout rejects new inputs instead of misreading them.
+pub const RWA_SCHEMA_VERSION: u16 = 3;
+
+/// Errors decoding a versioned `RwaClaim` input
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ClaimDecodeError {
+    /// Input is too short to contain the schema version prefix
+    #[error("input too short: expected a 2-byte schema version prefix")]
+    MissingVersion,
+
+    /// Input was encoded for a different schema version
+    #[error("schema version mismatch: expected {expected}, got {got}")]
+    SchemaVersionMismatch { expected: u16, got: u16 },
+
+    /// Claim bytes after the prefix are not a valid Borsh `RwaClaim`
+    #[error("malformed claim: {0}")]
+    Malformed(String),
+}
+
+/// `max_threshold` value meaning the claim has no upper bound
+pub const NO_MAX_THRESHOLD: u64 = u64::MAX;
+
+/// Errors checking a claim's balance against its `[threshold, max_threshold]` range
+///
+/// Variants carry only the public bounds, never the private balance.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ClaimRangeError {
+    /// The lower bound is above the upper bound
+    #[error("invalid range: min {min} exceeds max {max}")]
+    InvertedBounds { min: u64, max: u64 },
+
+    /// The balance is below the lower bound
+    #[error("balance is below the minimum {min}")]
+    BelowMin { min: u64 },
+
+    /// The balance is above the upper bound
+    #[error("balance is above the maximum {max}")]
+    AboveMax { max: u64 },
+}
+
+/// Errors checking a claim's timestamp against a `FreshnessWindow`
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ClaimFreshnessError {
+    /// The claim was signed longer ago than the window allows
+    #[error("claim timestamp {timestamp} is more than {max_age_secs}s before {verified_at}")]
+    Stale { timestamp: u64, verified_at: u64, max_age_secs: u64 },
+
+    /// The claim is dated after the verification time
+    #[error("claim timestamp {timestamp} is after verification time {verified_at}")]
+    FutureTimestamp { timestamp: u64, verified_at: u64 },
+}
+
+/// The freshness window a claim's timestamp was checked against.
+///
+/// Supplied by the caller and committed alongside the public values, so a
+/// verifier knows how recent the institution's attestation had to be.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FreshnessWindow {
+    /// Reference time (Unix seconds) the claim was checked at
+    pub verified_at: u64,
+
+    /// Maximum accepted age of the claim, in seconds
+    pub max_age_secs: u64,
+}
+
+impl FreshnessWindow {
+    /// Check that `timestamp` falls within `[verified_at - max_age_secs, verified_at]`.
+    pub fn check(&self, timestamp: u64) -> Result<(), ClaimFreshnessError> {
+        if timestamp > self.verified_at {
+            return Err(ClaimFreshnessError::FutureTimestamp {
+                timestamp,
+                verified_at: self.verified_at,
+            });
+        }
+        if self.verified_at - timestamp > self.max_age_secs {
+            return Err(ClaimFreshnessError::Stale {
+                timestamp,
+                verified_at: self.verified_at,
+                max_age_secs: self.max_age_secs,
+            });
+        }
+        Ok(())
+    }
+}
+
 /// A claim asserting that an institution holds a certain balance of real-world assets.
 ///
 /// This structure contains both private data (balance, signature) and public data
@@ -30,19 +123,20 @@ use serde_big_array::BigArray;
 /// - `institutional_pubkey`: Ed25519 public key identifying the institution
 /// - `balance`: Private balance amount (in smallest unit, e.g., cents or wei)
 /// - `threshold`: Minimum required balance for compliance
-/// - `signature`: Ed25519 signature over the balance, signed by the institution's private key
+/// - `timestamp`: When the institution attested the balance (Unix seconds)
+/// - `nonce`: Random value making each attestation unique
+/// - `signature`: Ed25519 signature over balance, timestamp and nonce
 ///
 /// ## Signature Scheme
 ///
 /// The signature is computed as:
 /// ```text
-/// signature = Ed25519.sign(private_key, balance.to_le_bytes())
+/// signature = Ed25519.sign(private_key, balance_le || timestamp_le || nonce)
 /// ```
 ///
-/// In production, the message should include additional context:
-/// - Timestamp (prevent replay attacks)
-/// - Nonce (prevent signature reuse)
-/// - Asset type identifier (if supporting multiple assets)
+/// The guest additionally checks `timestamp` against a caller-supplied
+/// `FreshnessWindow`, so an old signature cannot be replayed indefinitely.
+/// Multi-asset claims use `MultiAssetRwaClaim`.
 ///
 /// ## Example
 ///
@@ -54,10 +148,19 @@ use serde_big_array::BigArray;
 ///     institutional_pubkey: institution_pubkey,
 ///     balance: 1_000_000_00,  // $1M in cents
 ///     threshold: 500_000_00,   // $500K in cents
+///     timestamp: attested_at,
+///     nonce: random_nonce,
 ///     signature: institution_signature,
 /// };
 /// ```
-#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+///
+/// ## Equality and Hashing
+///
+/// Claims implement `Eq` and `Hash` so batch flows can dedup them and use them
+/// as cache keys. Because the hash covers the private `balance`, claims must not
+/// be used as keys in structures whose timing or iteration order is visible to
+/// an attacker.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct RwaClaim {
     /// Ed25519 public key of the institution (32 bytes)
@@ -82,14 +185,30 @@ pub struct RwaClaim {
     ///
     /// This will be committed to the public journal.
     /// Verifiers will know the institution met this threshold, but not by how much.
+    /// It is the lower bound of the claim's range (`min_threshold`).
     pub threshold: u64,
 
-    /// Ed25519 signature over the balance (64 bytes)
+    /// Maximum balance for the claimed tier (u64), inclusive
+    ///
+    /// Committed alongside `threshold`, so a claim can prove e.g. "$1M–$10M"
+    /// without revealing the balance. `NO_MAX_THRESHOLD` means unbounded,
+    /// which reduces to the plain `balance >= threshold` check.
+    pub max_threshold: u64,
+
+    /// Time the institution attested the balance (Unix seconds)
+    ///
+    /// Signed, and checked by the guest against a `FreshnessWindow`.
+    pub timestamp: u64,
+
+    /// Replay-protection nonce chosen by the institution (16 bytes)
+    pub nonce: [u8; 16],
+
+    /// Ed25519 signature over the claim message (64 bytes)
     ///
     /// This proves the institution actually signed off on this balance claim.
     /// The signature is verified inside the zkVM using the Ed25519 precompile.
     ///
-    /// Signature format: Ed25519(private_key, balance.to_le_bytes())
+    /// Signature format: Ed25519(private_key, `rwa_guest_lib::claim_message(balance, timestamp, nonce)`)
     #[cfg_attr(feature = "std", serde(with = "BigArray"))]
     pub signature: [u8; 64],
 }
@@ -97,12 +216,16 @@ pub struct RwaClaim {
 impl RwaClaim {
     /// Create a new RWA claim.
     ///
+    /// The claim has no upper bound; use `with_max_threshold` for a range claim.
+    ///
     /// # Arguments
     ///
     /// * `institutional_pubkey` - Ed25519 public key of the institution
     /// * `balance` - Private balance amount
     /// * `threshold` - Minimum required balance
-    /// * `signature` - Ed25519 signature over the balance
+    /// * `timestamp` - Attestation time (Unix seconds)
+    /// * `nonce` - Replay-protection nonce
+    /// * `signature` - Ed25519 signature over `message_to_sign()`
     ///
     /// # Example
     ///
@@ -111,6 +234,8 @@ impl RwaClaim {
     ///     institution_pubkey,
     ///     1_000_000_00,  // $1M
     ///     500_000_00,    // $500K threshold
+    ///     1_735_128_100, // attested at
+    ///     nonce,
     ///     signature,
     /// );
     /// ```
@@ -118,27 +243,448 @@ impl RwaClaim {
         institutional_pubkey: [u8; 32],
         balance: u64,
         threshold: u64,
+        timestamp: u64,
+        nonce: [u8; 16],
         signature: [u8; 64],
     ) -> Self {
         Self {
             institutional_pubkey,
             balance,
             threshold,
+            max_threshold: NO_MAX_THRESHOLD,
+            timestamp,
+            nonce,
             signature,
         }
     }
 
+    /// Set an inclusive upper bound, turning this into a range claim.
+    pub fn with_max_threshold(mut self, max_threshold: u64) -> Self {
+        self.max_threshold = max_threshold;
+        self
+    }
+
+    /// Check `threshold <= balance <= max_threshold`, as the guest does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClaimRangeError` if the bounds are inverted or the balance
+    /// falls outside them
+    pub fn check_range(&self) -> Result<(), ClaimRangeError> {
+        if self.threshold > self.max_threshold {
+            return Err(ClaimRangeError::InvertedBounds { min: self.threshold, max: self.max_threshold });
+        }
+        if self.balance < self.threshold {
+            return Err(ClaimRangeError::BelowMin { min: self.threshold });
+        }
+        if self.balance > self.max_threshold {
+            return Err(ClaimRangeError::AboveMax { max: self.max_threshold });
+        }
+        Ok(())
+    }
+
     /// Get the message that should be signed for this claim.
     ///
-    /// Currently, this is just the balance encoded as little-endian bytes.
-    /// In production, you should include additional context to prevent replay attacks.
+    /// `balance || timestamp || nonce`, as defined by `rwa_guest_lib::claim_message`,
+    /// which the guest program uses too, so host and guest agree byte-for-byte.
     ///
     /// # Returns
     ///
-    /// 8-byte array containing the balance in little-endian format
-    pub fn message_to_sign(&self) -> [u8; 8] {
-        self.balance.to_le_bytes()
+    /// 32-byte array: balance (u64 LE), timestamp (u64 LE), 16-byte nonce
+    pub fn message_to_sign(&self) -> [u8; rwa_guest_lib::CLAIM_MESSAGE_LEN] {
+        rwa_guest_lib::claim_message(self.balance, self.timestamp, &self.nonce)
+    }
+
+    /// Check this claim's timestamp against `window`, as the guest does.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClaimFreshnessError` if the claim is older than the window
+    /// allows or dated after `window.verified_at`
+    pub fn check_freshness(&self, window: &FreshnessWindow) -> Result<(), ClaimFreshnessError> {
+        window.check(self.timestamp)
+    }
+
+    /// Encode this claim as zkVM input: `RWA_SCHEMA_VERSION` (u16 LE) followed by Borsh.
+    pub fn to_input_bytes(&self) -> Vec<u8> {
+        let mut bytes = RWA_SCHEMA_VERSION.to_le_bytes().to_vec();
+        borsh::to_writer(&mut bytes, self).expect("Borsh serialization into a Vec cannot fail");
+        bytes
+    }
+
+    /// Decode a claim produced by `to_input_bytes`, checking the schema version.
+    ///
+    /// The guest program uses this and panics on error, so a host/guest layout
+    /// drift surfaces as "schema version mismatch" rather than garbage values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClaimDecodeError` if the prefix is missing, the version differs
+    /// from `RWA_SCHEMA_VERSION`, or the remaining bytes aren't a valid claim
+    pub fn from_input_bytes(bytes: &[u8]) -> Result<Self, ClaimDecodeError> {
+        if bytes.len() < 2 {
+            return Err(ClaimDecodeError::MissingVersion);
+        }
+        let got = u16::from_le_bytes([bytes[0], bytes[1]]);
+        if got != RWA_SCHEMA_VERSION {
+            return Err(ClaimDecodeError::SchemaVersionMismatch {
+                expected: RWA_SCHEMA_VERSION,
+                got,
+            });
+        }
+        Self::try_from_slice(&bytes[2..]).map_err(|e| ClaimDecodeError::Malformed(e.to_string()))
     }
+
+    /// Length of the unversioned Borsh form: pubkey + balance + threshold +
+    /// max_threshold + timestamp + nonce + signature
+    pub const WIRE_LEN: usize = 32 + 8 + 8 + 8 + 8 + 16 + 64;
+
+    /// Parse an unversioned Borsh claim.
+    ///
+    /// Never panics: inputs that are not exactly `WIRE_LEN` bytes are rejected.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, ClaimDecodeError> {
+        if bytes.len() != Self::WIRE_LEN {
+            return Err(ClaimDecodeError::Malformed(format!(
+                "claim must be {} bytes, got {}",
+                Self::WIRE_LEN,
+                bytes.len()
+            )));
+        }
+        Self::try_from_slice(bytes).map_err(|e| ClaimDecodeError::Malformed(e.to_string()))
+    }
+
+    /// Verify the institution's Ed25519 signature over `message_to_sign()`.
+    ///
+    /// This is the same check the guest program performs, so a claim that
+    /// fails here will also fail inside the zkVM.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the signature is valid for `institutional_pubkey`
+    pub fn verify_signature(&self) -> bool {
+        let Ok(verifying_key) = VerifyingKey::from_bytes(&self.institutional_pubkey) else {
+            return false;
+        };
+        let signature = Signature::from_bytes(&self.signature);
+        verifying_key
+            .verify(&self.message_to_sign(), &signature)
+            .is_ok()
+    }
+}
+
+/// Human-editable JSON form of an `RwaClaim`, with byte fields as hex strings
+#[cfg(feature = "std")]
+#[derive(Serialize, Deserialize)]
+struct RwaClaimJson {
+    institutional_pubkey: String,
+    balance: u64,
+    threshold: u64,
+    #[serde(default = "no_max_threshold")]
+    max_threshold: u64,
+    timestamp: u64,
+    nonce: String,
+    signature: String,
+}
+
+#[cfg(feature = "std")]
+fn no_max_threshold() -> u64 {
+    NO_MAX_THRESHOLD
+}
+
+#[cfg(feature = "std")]
+impl RwaClaim {
+    /// Encode this claim as pretty-printed JSON with hex-encoded byte fields.
+    ///
+    /// ```text
+    /// { "institutional_pubkey": "ab12…", "balance": 100000, "threshold": 50000,
+    ///   "max_threshold": 200000, "timestamp": 1735128100, "nonce": "ef56…", "signature": "cd34…" }
+    /// ```
+    ///
+    /// `max_threshold` may be omitted when parsing, meaning no upper bound.
+    pub fn to_json(&self) -> String {
+        let json = RwaClaimJson {
+            institutional_pubkey: hex::encode(self.institutional_pubkey),
+            balance: self.balance,
+            threshold: self.threshold,
+            max_threshold: self.max_threshold,
+            timestamp: self.timestamp,
+            nonce: hex::encode(self.nonce),
+            signature: hex::encode(self.signature),
+        };
+        serde_json::to_string_pretty(&json).expect("RwaClaimJson is always serializable")
+    }
+
+    /// Parse a claim from its JSON form. Hex fields may carry a `0x` prefix.
+    pub fn from_json(json: &str) -> Result<Self, ClaimDecodeError> {
+        let parsed: RwaClaimJson =
+            serde_json::from_str(json).map_err(|e| ClaimDecodeError::Malformed(e.to_string()))?;
+
+        fn decode_hex<const N: usize>(field: &str, value: &str) -> Result<[u8; N], ClaimDecodeError> {
+            let bytes = hex::decode(value.trim_start_matches("0x"))
+                .map_err(|e| ClaimDecodeError::Malformed(format!("{}: {}", field, e)))?;
+            let len = bytes.len();
+            bytes.try_into().map_err(|_| {
+                ClaimDecodeError::Malformed(format!("{}: expected {} bytes, got {}", field, N, len))
+            })
+        }
+
+        Ok(Self {
+            institutional_pubkey: decode_hex("institutional_pubkey", &parsed.institutional_pubkey)?,
+            balance: parsed.balance,
+            threshold: parsed.threshold,
+            max_threshold: parsed.max_threshold,
+            timestamp: parsed.timestamp,
+            nonce: decode_hex("nonce", &parsed.nonce)?,
+            signature: decode_hex("signature", &parsed.signature)?,
+        })
+    }
+}
+
+/// Convert a JSON claim into versioned zkVM input bytes (see `RwaClaim::to_input_bytes`).
+#[cfg(feature = "std")]
+pub fn claim_json_to_input_bytes(json: &str) -> Result<Vec<u8>, ClaimDecodeError> {
+    Ok(RwaClaim::from_json(json)?.to_input_bytes())
+}
+
+/// Convert versioned zkVM input bytes back into a JSON claim.
+#[cfg(feature = "std")]
+pub fn claim_input_bytes_to_json(bytes: &[u8]) -> Result<String, ClaimDecodeError> {
+    Ok(RwaClaim::from_input_bytes(bytes)?.to_json())
+}
+
+/// Remove duplicate claims from a batch, keeping the first occurrence of each.
+///
+/// Identical claims produce identical proofs, so batch provers should call this
+/// before proving to avoid redundant work.
+#[cfg(feature = "std")]
+pub fn dedup_claims(claims: Vec<RwaClaim>) -> Vec<RwaClaim> {
+    let mut seen = std::collections::HashSet::with_capacity(claims.len());
+    claims
+        .into_iter()
+        .filter(|claim| seen.insert(claim.clone()))
+        .collect()
+}
+
+/// Errors checking a claim's Merkle inclusion proof
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InclusionError {
+    /// The proof has more levels than a `u64` leaf index can address
+    #[error("merkle proof too deep: {depth} levels (max 64)")]
+    ProofTooDeep { depth: usize },
+
+    /// `leaf_index` doesn't fit in a tree of the proof's depth
+    #[error("leaf index {leaf_index} out of range for a tree of depth {depth}")]
+    LeafIndexOutOfRange { leaf_index: u64, depth: usize },
+
+    /// Walking the proof from the claim's leaf doesn't reach `merkle_root`
+    #[error("merkle root mismatch")]
+    RootMismatch,
+}
+
+/// An `RwaClaim` together with proof that its balance is in the institution's ledger.
+///
+/// The ledger is a Merkle tree of `rwa_guest_lib::account_leaf(account_id, balance)`
+/// leaves. The guest rebuilds the leaf from the signed balance, walks
+/// `merkle_proof` up to the root and commits the root, so a verifier learns
+/// the balance came from a specific ledger snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RwaClaimWithProof {
+    /// The signed balance claim
+    pub claim: RwaClaim,
+
+    /// Ledger account the balance belongs to
+    pub account_id: [u8; 32],
+
+    /// Root of the institution's balance tree
+    pub merkle_root: [u8; 32],
+
+    /// Sibling hashes from the leaf up to the root
+    pub merkle_proof: Vec<[u8; 32]>,
+
+    /// Position of the account's leaf in the tree
+    pub leaf_index: u64,
+}
+
+impl RwaClaimWithProof {
+    /// Leaf hash of the claimed account and balance
+    pub fn leaf(&self) -> [u8; 32] {
+        rwa_guest_lib::account_leaf(&self.account_id, self.claim.balance)
+    }
+
+    /// Check the Merkle proof as the guest does, returning the root it commits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InclusionError` if the proof is deeper than 64 levels, the
+    /// leaf index has bits beyond the proof depth, or the walk doesn't reach
+    /// `merkle_root`
+    pub fn verify_inclusion(&self) -> Result<[u8; 32], InclusionError> {
+        let depth = self.merkle_proof.len();
+        if depth > 64 {
+            return Err(InclusionError::ProofTooDeep { depth });
+        }
+        // Higher index bits are never read by the walk; reject them so each
+        // leaf has exactly one valid index
+        if depth < 64 && self.leaf_index >> depth != 0 {
+            return Err(InclusionError::LeafIndexOutOfRange { leaf_index: self.leaf_index, depth });
+        }
+        if !rwa_guest_lib::verify_merkle_path(self.leaf(), self.leaf_index, &self.merkle_proof, &self.merkle_root) {
+            return Err(InclusionError::RootMismatch);
+        }
+        Ok(self.merkle_root)
+    }
+}
+
+/// Errors checking a `MultiAssetRwaClaim`
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AssetComplianceError {
+    /// The claim lists no assets
+    #[error("multi-asset claim has no assets")]
+    NoAssets,
+
+    /// An asset's signature does not verify against the institution's key
+    #[error("invalid signature for asset {asset_id:?}")]
+    InvalidSignature { asset_id: [u8; 4] },
+
+    /// An asset's balance is below its threshold
+    #[error("asset {asset_id:?} is below its threshold")]
+    BelowThreshold { asset_id: [u8; 4] },
+
+    /// The claim's timestamp is outside the freshness window
+    #[error(transparent)]
+    Freshness(#[from] ClaimFreshnessError),
+}
+
+/// One asset within a `MultiAssetRwaClaim`.
+///
+/// Each asset carries its own threshold and its own institutional signature
+/// over `rwa_guest_lib::asset_balance_message(asset_id, balance, timestamp, nonce)`,
+/// where `timestamp` and `nonce` are the enclosing claim's.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetEntry {
+    /// Short asset identifier (e.g. `*b"USDC"`)
+    pub asset_id: [u8; 4],
+
+    /// Private balance of this asset
+    pub balance: u64,
+
+    /// Minimum required balance of this asset
+    pub threshold: u64,
+
+    /// Ed25519 signature over `asset_id || balance || timestamp || nonce`
+    #[cfg_attr(feature = "std", serde(with = "BigArray"))]
+    pub signature: [u8; 64],
+}
+
+impl AssetEntry {
+    /// Get the message the institution signs for this asset within a claim
+    /// attested at `timestamp` with `nonce`.
+    pub fn message_to_sign(&self, timestamp: u64, nonce: &[u8; 16]) -> [u8; rwa_guest_lib::ASSET_BALANCE_MESSAGE_LEN] {
+        rwa_guest_lib::asset_balance_message(&self.asset_id, self.balance, timestamp, nonce)
+    }
+
+    /// Verify this asset's signature against the institution's public key and
+    /// the enclosing claim's `timestamp` and `nonce`.
+    pub fn verify_signature(&self, institutional_pubkey: &[u8; 32], timestamp: u64, nonce: &[u8; 16]) -> bool {
+        let Ok(verifying_key) = VerifyingKey::from_bytes(institutional_pubkey) else {
+            return false;
+        };
+        let signature = Signature::from_bytes(&self.signature);
+        verifying_key
+            .verify(&self.message_to_sign(timestamp, nonce), &signature)
+            .is_ok()
+    }
+}
+
+/// A claim covering several assets held by one institution.
+///
+/// Kept separate from `RwaClaim` so single-asset inputs keep their existing
+/// Borsh layout. The guest checks every entry, and the whole claim fails if
+/// any one asset is unsigned or below its threshold, or the claim is outside
+/// the caller's `FreshnessWindow`. Every asset signature covers the claim's
+/// `timestamp` and `nonce`, so entries can't be lifted from an older claim.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MultiAssetRwaClaim {
+    /// Ed25519 public key of the institution (32 bytes)
+    pub institutional_pubkey: [u8; 32],
+
+    /// Time the institution attested the balances (Unix seconds)
+    ///
+    /// Signed into every asset entry, and checked against a `FreshnessWindow`.
+    pub timestamp: u64,
+
+    /// Replay-protection nonce chosen by the institution, signed into every asset entry
+    pub nonce: [u8; 16],
+
+    /// Per-asset balances, thresholds and signatures, in commitment order
+    pub assets: Vec<AssetEntry>,
+}
+
+impl MultiAssetRwaClaim {
+    /// Run the guest's compliance checks and return the public values it would commit.
+    ///
+    /// The claim's timestamp is checked against `freshness` first, then
+    /// entries are checked in order; the first failing asset is reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AssetComplianceError` if there are no assets, the claim is
+    /// outside `freshness`, or any asset has an invalid signature or a
+    /// balance below its threshold
+    pub fn check_compliance(&self, freshness: &FreshnessWindow) -> Result<MultiAssetPublicValues, AssetComplianceError> {
+        if self.assets.is_empty() {
+            return Err(AssetComplianceError::NoAssets);
+        }
+        freshness.check(self.timestamp)?;
+
+        let mut assets = Vec::with_capacity(self.assets.len());
+        for entry in &self.assets {
+            if !entry.verify_signature(&self.institutional_pubkey, self.timestamp, &self.nonce) {
+                return Err(AssetComplianceError::InvalidSignature { asset_id: entry.asset_id });
+            }
+            if entry.balance < entry.threshold {
+                return Err(AssetComplianceError::BelowThreshold { asset_id: entry.asset_id });
+            }
+            assets.push(AssetThreshold {
+                asset_id: entry.asset_id,
+                threshold: entry.threshold,
+            });
+        }
+
+        Ok(MultiAssetPublicValues {
+            institutional_pubkey: self.institutional_pubkey,
+            assets,
+            freshness: *freshness,
+        })
+    }
+}
+
+/// An asset id and the threshold it met, as committed to the journal
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetThreshold {
+    /// Asset identifier
+    pub asset_id: [u8; 4],
+
+    /// The threshold that was met (but not the actual balance)
+    pub threshold: u64,
+}
+
+/// Public values committed by the guest for a `MultiAssetRwaClaim`.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MultiAssetPublicValues {
+    /// Ed25519 public key of the institution that passed compliance
+    pub institutional_pubkey: [u8; 32],
+
+    /// Each asset's id and threshold, in claim order
+    pub assets: Vec<AssetThreshold>,
+
+    /// The freshness window the claim's timestamp was checked against
+    pub freshness: FreshnessWindow,
 }
 
 /// Public values committed to the journal by the guest program.
@@ -153,16 +699,60 @@ pub struct RwaPublicValues {
 
     /// The threshold that was met (but not the actual balance)
     pub threshold: u64,
+
+    /// The upper bound the balance was within (`NO_MAX_THRESHOLD` if unbounded)
+    pub max_threshold: u64,
+
+    /// The freshness window the claim's timestamp was checked against
+    pub freshness: FreshnessWindow,
 }
 
 impl RwaPublicValues {
-    /// Create new public values from a claim.
+    /// Length of the Borsh wire form: 32-byte pubkey + u64 LE threshold +
+    /// u64 LE max_threshold + u64 LE `verified_at` + u64 LE `max_age_secs`
+    pub const WIRE_LEN: usize = 64;
+
+    /// Parse public values from their wire form, e.g. a proof's journal.
     ///
-    /// This extracts only the public portions of an RwaClaim.
-    pub fn from_claim(claim: &RwaClaim) -> Self {
+    /// Never panics: any input other than exactly `WIRE_LEN` bytes is an error.
+    pub fn from_wire(bytes: &[u8]) -> Result<Self, ClaimDecodeError> {
+        if bytes.len() != Self::WIRE_LEN {
+            return Err(ClaimDecodeError::Malformed(format!(
+                "public values must be {} bytes, got {}",
+                Self::WIRE_LEN,
+                bytes.len()
+            )));
+        }
+        let (pubkey, rest) = bytes.split_at(32);
+        let mut institutional_pubkey = [0u8; 32];
+        institutional_pubkey.copy_from_slice(pubkey);
+        let read_u64 = |offset: usize| {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&rest[offset..offset + 8]);
+            u64::from_le_bytes(word)
+        };
+
+        Ok(Self {
+            institutional_pubkey,
+            threshold: read_u64(0),
+            max_threshold: read_u64(8),
+            freshness: FreshnessWindow {
+                verified_at: read_u64(16),
+                max_age_secs: read_u64(24),
+            },
+        })
+    }
+
+    /// Create new public values from a claim and the window it was checked against.
+    ///
+    /// This extracts only the public portions of an RwaClaim; the balance,
+    /// timestamp and nonce stay private.
+    pub fn from_claim(claim: &RwaClaim, freshness: FreshnessWindow) -> Self {
         Self {
             institutional_pubkey: claim.institutional_pubkey,
             threshold: claim.threshold,
+            max_threshold: claim.max_threshold,
+            freshness,
         }
     }
 }
@@ -177,6 +767,8 @@ mod tests {
             [1u8; 32],  // Mock pubkey
             1_000_000,  // $1M balance
             500_000,    // $500K threshold
+            1_735_128_100, // Attested at
+            [9u8; 16],  // Mock nonce
             [2u8; 64],  // Mock signature
         );
 
@@ -184,18 +776,369 @@ mod tests {
         assert_eq!(claim.threshold, 500_000);
     }
 
+    #[test]
+    fn test_json_borsh_round_trip() {
+        let claim = RwaClaim::new([0xAB; 32], 1_000_000, 500_000, 1_735_128_100, [9u8; 16], [0xCD; 64]);
+
+        let json = claim.to_json();
+        assert!(json.contains(&"ab".repeat(32)));
+
+        let input = claim_json_to_input_bytes(&json).unwrap();
+        assert_eq!(input, claim.to_input_bytes());
+        assert_eq!(claim_input_bytes_to_json(&input).unwrap(), json);
+
+        let prefixed = json.replace(&"ab".repeat(32), &format!("0x{}", "ab".repeat(32)));
+        assert_eq!(RwaClaim::from_json(&prefixed).unwrap(), claim);
+    }
+
+    #[test]
+    fn test_from_json_rejects_short_signature() {
+        let json = r#"{"institutional_pubkey":"00","balance":1,"threshold":1,"timestamp":1,"nonce":"00","signature":"00"}"#;
+        assert!(matches!(RwaClaim::from_json(json), Err(ClaimDecodeError::Malformed(_))));
+    }
+
+    #[test]
+    fn test_public_values_from_wire_matches_borsh() {
+        let freshness = FreshnessWindow { verified_at: 1_735_128_100, max_age_secs: 3600 };
+        let values = RwaPublicValues { institutional_pubkey: [3u8; 32], threshold: 42, max_threshold: 99, freshness };
+        let wire = borsh::to_vec(&values).unwrap();
+        assert_eq!(wire.len(), RwaPublicValues::WIRE_LEN);
+        let parsed = RwaPublicValues::from_wire(&wire).unwrap();
+
+        assert_eq!(parsed.institutional_pubkey, [3u8; 32]);
+        assert_eq!(parsed.threshold, 42);
+        assert_eq!(parsed.max_threshold, 99);
+        assert_eq!(parsed.freshness, freshness);
+        for len in [0, 32, 33, 40, 56, 63, 65] {
+            assert!(RwaPublicValues::from_wire(&vec![0u8; len]).is_err());
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_parsers_never_panic(bytes in proptest::collection::vec(proptest::num::u8::ANY, 0..256)) {
+            let _ = RwaPublicValues::from_wire(&bytes);
+            let _ = RwaClaim::try_from_bytes(&bytes);
+            let _ = RwaClaim::from_input_bytes(&bytes);
+        }
+
+        #[test]
+        fn prop_claim_bytes_round_trip(
+            pubkey in proptest::array::uniform32(proptest::num::u8::ANY),
+            balance in proptest::num::u64::ANY,
+            threshold in proptest::num::u64::ANY,
+            timestamp in proptest::num::u64::ANY,
+        ) {
+            let claim = RwaClaim::new(pubkey, balance, threshold, timestamp, [5u8; 16], [7u8; 64]);
+            let bytes = borsh::to_vec(&claim).unwrap();
+            proptest::prop_assert_eq!(RwaClaim::try_from_bytes(&bytes).unwrap(), claim);
+        }
+    }
+
+    #[test]
+    fn test_dedup_claims_in_batch() {
+        let a = RwaClaim::new([1u8; 32], 1_000, 500, 1_735_128_100, [9u8; 16], [2u8; 64]);
+        let b = RwaClaim::new([1u8; 32], 2_000, 500, 1_735_128_100, [9u8; 16], [2u8; 64]);
+
+        let batch = vec![a.clone(), b.clone(), a.clone(), b.clone(), a.clone()];
+        let unique = dedup_claims(batch);
+
+        assert_eq!(unique, vec![a.clone(), b]);
+
+        let mut cache = std::collections::HashMap::new();
+        cache.insert(a.clone(), "proof");
+        assert_eq!(cache.get(&a), Some(&"proof"));
+    }
+
     #[test]
     fn test_message_to_sign() {
-        let claim = RwaClaim::new([0u8; 32], 12345, 10000, [0u8; 64]);
+        let claim = RwaClaim::new([0u8; 32], 12345, 10000, 1_735_128_100, [9u8; 16], [0u8; 64]);
         let message = claim.message_to_sign();
 
-        // Verify it's the little-endian encoding of 12345
-        assert_eq!(message, 12345u64.to_le_bytes());
+        // balance || timestamp || nonce, integers little-endian
+        assert_eq!(&message[..8], &12345u64.to_le_bytes());
+        assert_eq!(&message[8..16], &1_735_128_100u64.to_le_bytes());
+        assert_eq!(&message[16..], &[9u8; 16]);
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let signing_key = SigningKey::from_bytes(&[42u8; 32]);
+        let mut claim = RwaClaim::new(
+            signing_key.verifying_key().to_bytes(),
+            1_000_000,
+            500_000,
+            1_735_128_100,
+            [9u8; 16],
+            [0u8; 64],
+        );
+        claim.signature = signing_key.sign(&claim.message_to_sign()).to_bytes();
+        assert!(claim.verify_signature());
+
+        // Changing the balance, timestamp or nonce invalidates the signature
+        for tamper in [
+            |c: &mut RwaClaim| c.balance += 1,
+            |c: &mut RwaClaim| c.timestamp += 1,
+            |c: &mut RwaClaim| c.nonce[0] ^= 1,
+        ] {
+            let mut tampered = claim.clone();
+            tamper(&mut tampered);
+            assert!(!tampered.verify_signature());
+        }
+    }
+
+    #[test]
+    fn test_range_claim() {
+        // $1M–$10M tier, in cents
+        let claim = |balance| {
+            RwaClaim::new([1u8; 32], balance, 100_000_000, 1_735_128_100, [9u8; 16], [2u8; 64])
+                .with_max_threshold(1_000_000_000)
+        };
+
+        assert!(claim(500_000_000).check_range().is_ok());
+        assert!(claim(100_000_000).check_range().is_ok());
+        assert!(claim(1_000_000_000).check_range().is_ok());
+        assert_eq!(
+            claim(99_999_999).check_range(),
+            Err(ClaimRangeError::BelowMin { min: 100_000_000 })
+        );
+        assert_eq!(
+            claim(1_000_000_001).check_range(),
+            Err(ClaimRangeError::AboveMax { max: 1_000_000_000 })
+        );
+        assert!(matches!(
+            claim(500_000_000).with_max_threshold(1).check_range(),
+            Err(ClaimRangeError::InvertedBounds { .. })
+        ));
+
+        let public_values = RwaPublicValues::from_claim(&claim(500_000_000), FreshnessWindow::default());
+        assert_eq!(public_values.threshold, 100_000_000);
+        assert_eq!(public_values.max_threshold, 1_000_000_000);
+
+        // Unbounded by default, and when omitted from JSON
+        let plain = RwaClaim::new([1u8; 32], u64::MAX, 1, 0, [0u8; 16], [0u8; 64]);
+        assert_eq!(plain.max_threshold, NO_MAX_THRESHOLD);
+        assert!(plain.check_range().is_ok());
+        let json = plain.to_json().replace(&format!("\"max_threshold\": {},", u64::MAX), "");
+        assert_eq!(RwaClaim::from_json(&json).unwrap(), plain);
+    }
+
+    #[test]
+    fn test_freshness_window() {
+        let claim = RwaClaim::new([1u8; 32], 1_000, 500, 1_735_128_100, [9u8; 16], [2u8; 64]);
+        let window = FreshnessWindow { verified_at: 1_735_128_100 + 3600, max_age_secs: 3600 };
+        assert!(claim.check_freshness(&window).is_ok());
+
+        // An hour and a second old: rejected even though the signature is unchanged
+        let late = FreshnessWindow { verified_at: window.verified_at + 1, ..window };
+        assert_eq!(
+            claim.check_freshness(&late),
+            Err(ClaimFreshnessError::Stale {
+                timestamp: 1_735_128_100,
+                verified_at: late.verified_at,
+                max_age_secs: 3600,
+            })
+        );
+
+        let early = FreshnessWindow { verified_at: 1_735_128_099, max_age_secs: 3600 };
+        assert!(matches!(
+            claim.check_freshness(&early),
+            Err(ClaimFreshnessError::FutureTimestamp { .. })
+        ));
+
+        let public_values = RwaPublicValues::from_claim(&claim, window);
+        assert_eq!(public_values.freshness, window);
+    }
+
+    const ATTESTED_AT: u64 = 1_735_128_100;
+    const WINDOW: FreshnessWindow = FreshnessWindow { verified_at: ATTESTED_AT + 60, max_age_secs: 3600 };
+
+    fn signed_asset(
+        signing_key: &ed25519_dalek::SigningKey,
+        (timestamp, nonce): (u64, [u8; 16]),
+        (asset_id, balance, threshold): ([u8; 4], u64, u64),
+    ) -> AssetEntry {
+        use ed25519_dalek::Signer;
+
+        let mut entry = AssetEntry { asset_id, balance, threshold, signature: [0u8; 64] };
+        entry.signature = signing_key.sign(&entry.message_to_sign(timestamp, &nonce)).to_bytes();
+        entry
+    }
+
+    /// Claim attested at `ATTESTED_AT` with nonce `[9; 16]`, every asset signed by `[7; 32]`
+    fn multi_claim(assets: &[([u8; 4], u64, u64)]) -> MultiAssetRwaClaim {
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let (timestamp, nonce) = (ATTESTED_AT, [9u8; 16]);
+        MultiAssetRwaClaim {
+            institutional_pubkey: signing_key.verifying_key().to_bytes(),
+            timestamp,
+            nonce,
+            assets: assets
+                .iter()
+                .map(|&asset| signed_asset(&signing_key, (timestamp, nonce), asset))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_multi_asset_all_compliant() {
+        let claim = multi_claim(&[(*b"USDC", 1_000, 500), (*b"TBIL", 200, 200)]);
+
+        let public = claim.check_compliance(&WINDOW).unwrap();
+        assert_eq!(public.institutional_pubkey, claim.institutional_pubkey);
+        assert_eq!(
+            public.assets,
+            vec![
+                AssetThreshold { asset_id: *b"USDC", threshold: 500 },
+                AssetThreshold { asset_id: *b"TBIL", threshold: 200 },
+            ]
+        );
+        assert_eq!(public.freshness, WINDOW);
+    }
+
+    #[test]
+    fn test_multi_asset_one_below_threshold_fails() {
+        let claim = multi_claim(&[(*b"USDC", 1_000, 500), (*b"TBIL", 199, 200), (*b"GOLD", 50, 10)]);
+
+        assert_eq!(
+            claim.check_compliance(&WINDOW),
+            Err(AssetComplianceError::BelowThreshold { asset_id: *b"TBIL" })
+        );
+    }
+
+    #[test]
+    fn test_multi_asset_signature_bound_to_asset_id() {
+        let mut claim = multi_claim(&[(*b"USDC", 1_000, 500)]);
+        claim.assets[0].asset_id = *b"TBIL";
+
+        assert_eq!(
+            claim.check_compliance(&WINDOW),
+            Err(AssetComplianceError::InvalidSignature { asset_id: *b"TBIL" })
+        );
+
+        let empty = MultiAssetRwaClaim { assets: vec![], ..claim };
+        assert_eq!(empty.check_compliance(&WINDOW), Err(AssetComplianceError::NoAssets));
+    }
+
+    #[test]
+    fn test_multi_asset_stale_claim_rejected() {
+        let claim = multi_claim(&[(*b"USDC", 1_000, 500)]);
+
+        // Replaying the whole claim an hour and a second after attestation
+        let late = FreshnessWindow { verified_at: ATTESTED_AT + 3601, max_age_secs: 3600 };
+        assert!(matches!(
+            claim.check_compliance(&late),
+            Err(AssetComplianceError::Freshness(ClaimFreshnessError::Stale { .. }))
+        ));
+
+        // Re-dating the claim doesn't help: the signatures cover the timestamp
+        let mut redated = claim;
+        redated.timestamp = late.verified_at;
+        assert_eq!(
+            redated.check_compliance(&late),
+            Err(AssetComplianceError::InvalidSignature { asset_id: *b"USDC" })
+        );
+    }
+
+    #[test]
+    fn test_multi_asset_reused_nonce_rejected() {
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let mut claim = multi_claim(&[(*b"USDC", 1_000, 500)]);
+
+        // A TBIL attestation from an earlier claim, carried over with its old nonce
+        let old = signed_asset(&signing_key, (ATTESTED_AT - 600, [1u8; 16]), (*b"TBIL", 200, 200));
+        claim.assets.push(old.clone());
+        assert_eq!(
+            claim.check_compliance(&WINDOW),
+            Err(AssetComplianceError::InvalidSignature { asset_id: *b"TBIL" })
+        );
+
+        // Reusing the earlier claim's nonce makes the fresh USDC entry fail instead
+        claim.nonce = [1u8; 16];
+        assert_eq!(
+            claim.check_compliance(&WINDOW),
+            Err(AssetComplianceError::InvalidSignature { asset_id: *b"USDC" })
+        );
+    }
+
+    #[test]
+    fn test_multi_asset_borsh_deterministic() {
+        let claim = MultiAssetRwaClaim {
+            institutional_pubkey: [1u8; 32],
+            timestamp: ATTESTED_AT,
+            nonce: [9u8; 16],
+            assets: vec![
+                AssetEntry { asset_id: *b"USDC", balance: 1, threshold: 2, signature: [3u8; 64] },
+                AssetEntry { asset_id: *b"TBIL", balance: 4, threshold: 5, signature: [6u8; 64] },
+            ],
+        };
+
+        let bytes = borsh::to_vec(&claim).unwrap();
+        assert_eq!(bytes, borsh::to_vec(&claim.clone()).unwrap());
+        // pubkey + timestamp + nonce + u32 length prefix + two 84-byte entries
+        assert_eq!(bytes.len(), 32 + 8 + 16 + 4 + 2 * (4 + 8 + 8 + 64));
+        assert_eq!(MultiAssetRwaClaim::try_from_slice(&bytes).unwrap(), claim);
+    }
+
+    /// Four-account ledger with the claimed account at `index`
+    fn ledger_proof(index: u64) -> RwaClaimWithProof {
+        let claim = RwaClaim::new([1u8; 32], 1_000, 500, 1_735_128_100, [9u8; 16], [2u8; 64]);
+        let account_id = [0xA1; 32];
+        let mut leaves = [
+            rwa_guest_lib::account_leaf(&[0xB0; 32], 10),
+            rwa_guest_lib::account_leaf(&[0xC0; 32], 20),
+            rwa_guest_lib::account_leaf(&[0xD0; 32], 30),
+            rwa_guest_lib::account_leaf(&[0xE0; 32], 40),
+        ];
+        leaves[index as usize] = rwa_guest_lib::account_leaf(&account_id, claim.balance);
+
+        let left = rwa_guest_lib::merkle_node(&leaves[0], &leaves[1]);
+        let right = rwa_guest_lib::merkle_node(&leaves[2], &leaves[3]);
+        let (sibling, uncle) = match index {
+            0 => (leaves[1], right),
+            1 => (leaves[0], right),
+            2 => (leaves[3], left),
+            _ => (leaves[2], left),
+        };
+
+        RwaClaimWithProof {
+            claim,
+            account_id,
+            merkle_root: rwa_guest_lib::merkle_node(&left, &right),
+            merkle_proof: vec![sibling, uncle],
+            leaf_index: index,
+        }
+    }
+
+    #[test]
+    fn test_merkle_inclusion() {
+        for index in 0..4 {
+            let proof = ledger_proof(index);
+            assert_eq!(proof.verify_inclusion(), Ok(proof.merkle_root));
+        }
+    }
+
+    #[test]
+    fn test_merkle_inclusion_wrong_index_fails() {
+        let mut proof = ledger_proof(2);
+        proof.leaf_index = 3;
+        assert_eq!(proof.verify_inclusion(), Err(InclusionError::RootMismatch));
+
+        // Aliased index with a bit above the proof depth
+        proof.leaf_index = 2 | (1 << 2);
+        assert!(matches!(proof.verify_inclusion(), Err(InclusionError::LeafIndexOutOfRange { .. })));
+
+        // A different balance produces a different leaf
+        let mut proof = ledger_proof(2);
+        proof.claim.balance += 1;
+        assert_eq!(proof.verify_inclusion(), Err(InclusionError::RootMismatch));
     }
 
     #[test]
     fn test_borsh_serialization() {
-        let claim = RwaClaim::new([1u8; 32], 1_000_000, 500_000, [2u8; 64]);
+        let claim = RwaClaim::new([1u8; 32], 1_000_000, 500_000, 1_735_128_100, [9u8; 16], [2u8; 64]);
 
         // Serialize and deserialize
         let bytes = borsh::to_vec(&claim).unwrap();
@@ -205,10 +1148,43 @@ mod tests {
         assert_eq!(claim.threshold, deserialized.threshold);
     }
 
+    #[test]
+    fn test_input_bytes_roundtrip() {
+        let claim = RwaClaim::new([1u8; 32], 1_000_000, 500_000, 1_735_128_100, [9u8; 16], [2u8; 64]);
+        let bytes = claim.to_input_bytes();
+
+        assert_eq!(&bytes[..2], &RWA_SCHEMA_VERSION.to_le_bytes());
+        let decoded = RwaClaim::from_input_bytes(&bytes).unwrap();
+        assert_eq!(decoded.balance, claim.balance);
+        assert_eq!(decoded.threshold, claim.threshold);
+    }
+
+    #[test]
+    fn test_input_bytes_version_mismatch() {
+        let claim = RwaClaim::new([1u8; 32], 1_000_000, 500_000, 1_735_128_100, [9u8; 16], [2u8; 64]);
+        let mut bytes = claim.to_input_bytes();
+        bytes[..2].copy_from_slice(&(RWA_SCHEMA_VERSION + 1).to_le_bytes());
+
+        let err = RwaClaim::from_input_bytes(&bytes).unwrap_err();
+        assert_eq!(
+            err,
+            ClaimDecodeError::SchemaVersionMismatch {
+                expected: RWA_SCHEMA_VERSION,
+                got: RWA_SCHEMA_VERSION + 1,
+            }
+        );
+        assert!(err.to_string().contains("schema version mismatch"));
+
+        // Unversioned (legacy) Borsh input is rejected rather than misread
+        let legacy = borsh::to_vec(&claim).unwrap();
+        assert!(RwaClaim::from_input_bytes(&legacy).is_err());
+        assert_eq!(RwaClaim::from_input_bytes(&[1]).unwrap_err(), ClaimDecodeError::MissingVersion);
+    }
+
     #[test]
     fn test_public_values_extraction() {
-        let claim = RwaClaim::new([1u8; 32], 1_000_000, 500_000, [2u8; 64]);
-        let public_values = RwaPublicValues::from_claim(&claim);
+        let claim = RwaClaim::new([1u8; 32], 1_000_000, 500_000, 1_735_128_100, [9u8; 16], [2u8; 64]);
+        let public_values = RwaPublicValues::from_claim(&claim, FreshnessWindow::default());
 
         assert_eq!(public_values.institutional_pubkey, claim.institutional_pubkey);
         assert_eq!(public_values.threshold, claim.threshold);
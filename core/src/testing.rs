@@ -0,0 +1,366 @@
+//! # Testing Utilities
+//!
+//! A reusable `PrivacyEngine` mock for tests and benchmarks, available with the
+//! `testing` feature. Downstream crates building on `PrivacyEngine` can use it
+//! instead of writing their own.
+//!
+//! ## Example
+//!
+//! ```
+//! use universal_privacy_engine_core::testing::MockEngine;
+//! use universal_privacy_engine_core::PrivacyEngine;
+//!
+//! let engine = MockEngine::builder()
+//!     .proof(vec![0xCA, 0xFE])
+//!     .verify_returns(true)
+//!     .build();
+//!
+//! let receipt = engine.prove(b"input").unwrap();
+//! assert_eq!(receipt.public_values, b"input");
+//! assert!(engine.verify(&receipt).unwrap());
+//! assert_eq!(engine.prove_calls(), 1);
+//! assert_eq!(engine.verify_calls(), 1);
+//! ```
+
+use crate::{ChainType, PrivacyEngine, PrivacyEngineError, ProofReceipt, ProofType};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Proof bytes returned by a default `MockEngine`
+pub const DEFAULT_MOCK_PROOF: [u8; 4] = [0xCA, 0xFE, 0xBA, 0xBE];
+
+/// What `MockEngine::verify` returns
+#[derive(Debug, Clone)]
+enum VerifyOutcome {
+    /// Valid iff the receipt carries the engine's configured proof bytes
+    MatchProof,
+
+    /// Always return this result
+    Fixed(bool),
+
+    /// Always fail with `PrivacyEngineError::VerificationFailed`
+    Error(String),
+}
+
+/// Mock `PrivacyEngine` that echoes its input as public values
+///
+/// Counts calls to `prove` and `verify` so tests can assert the engine was
+/// (or wasn't) used.
+#[derive(Debug)]
+pub struct MockEngine {
+    proof: Vec<u8>,
+    metadata: Vec<u8>,
+    verify_outcome: VerifyOutcome,
+    prove_calls: AtomicUsize,
+    verify_calls: AtomicUsize,
+}
+
+impl MockEngine {
+    /// Mock returning `DEFAULT_MOCK_PROOF` that verifies only its own proofs
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Mock whose `verify` always returns `Ok(false)`, simulating an invalid proof
+    pub fn with_failing_verify() -> Self {
+        Self::builder().verify_returns(false).build()
+    }
+
+    /// Start configuring a mock
+    pub fn builder() -> MockEngineBuilder {
+        MockEngineBuilder::default()
+    }
+
+    /// Number of times `prove` has been called
+    pub fn prove_calls(&self) -> usize {
+        self.prove_calls.load(Ordering::SeqCst)
+    }
+
+    /// Number of times `verify` has been called
+    pub fn verify_calls(&self) -> usize {
+        self.verify_calls.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for MockEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrivacyEngine for MockEngine {
+    fn prove(&self, input: &[u8]) -> Result<ProofReceipt, PrivacyEngineError> {
+        self.prove_calls.fetch_add(1, Ordering::SeqCst);
+        Ok(ProofReceipt {
+            proof_type: ProofType::ZkProof,
+            proof: self.proof.clone(),
+            public_values: input.to_vec(),
+            metadata: self.metadata.clone(),
+        })
+    }
+
+    fn verify(&self, receipt: &ProofReceipt) -> Result<bool, PrivacyEngineError> {
+        self.verify_calls.fetch_add(1, Ordering::SeqCst);
+        match &self.verify_outcome {
+            VerifyOutcome::MatchProof => Ok(receipt.proof == self.proof),
+            VerifyOutcome::Fixed(valid) => Ok(*valid),
+            VerifyOutcome::Error(reason) => Err(PrivacyEngineError::VerificationFailed(reason.clone())),
+        }
+    }
+
+    fn export_verifier(&self, _chain: ChainType) -> Result<Vec<u8>, PrivacyEngineError> {
+        Ok(vec![])
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn backend_version(&self) -> &'static str {
+        env!("CARGO_PKG_VERSION")
+    }
+}
+
+/// Builder for `MockEngine`
+#[derive(Debug, Clone)]
+pub struct MockEngineBuilder {
+    proof: Vec<u8>,
+    metadata: Vec<u8>,
+    verify_outcome: VerifyOutcome,
+}
+
+impl Default for MockEngineBuilder {
+    fn default() -> Self {
+        Self {
+            proof: DEFAULT_MOCK_PROOF.to_vec(),
+            metadata: b"mock_engine".to_vec(),
+            verify_outcome: VerifyOutcome::MatchProof,
+        }
+    }
+}
+
+impl MockEngineBuilder {
+    /// Proof bytes placed in every receipt
+    pub fn proof(mut self, proof: Vec<u8>) -> Self {
+        self.proof = proof;
+        self
+    }
+
+    /// Metadata placed in every receipt
+    pub fn metadata(mut self, metadata: Vec<u8>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Make `verify` always return `Ok(valid)`
+    pub fn verify_returns(mut self, valid: bool) -> Self {
+        self.verify_outcome = VerifyOutcome::Fixed(valid);
+        self
+    }
+
+    /// Make `verify` always fail with `VerificationFailed(reason)`
+    pub fn verify_fails(mut self, reason: impl Into<String>) -> Self {
+        self.verify_outcome = VerifyOutcome::Error(reason.into());
+        self
+    }
+
+    /// Build the mock
+    pub fn build(self) -> MockEngine {
+        MockEngine {
+            proof: self.proof,
+            metadata: self.metadata,
+            verify_outcome: self.verify_outcome,
+            prove_calls: AtomicUsize::new(0),
+            verify_calls: AtomicUsize::new(0),
+        }
+    }
+}
+
+/// Failure-injecting wrapper around another `PrivacyEngine`
+///
+/// Fails `prove` and `verify` calls according to its policy, otherwise
+/// delegating to the wrapped engine. Each method counts its calls separately
+/// (1-based), so `fail_every(3)` fails the 3rd, 6th, ... call of each.
+///
+/// ```
+/// use universal_privacy_engine_core::testing::{FaultyEngine, MockEngine};
+/// use universal_privacy_engine_core::PrivacyEngine;
+///
+/// let engine = FaultyEngine::new(MockEngine::new()).fail_first(1);
+/// assert!(engine.prove(b"x").is_err());
+/// assert!(engine.prove(b"x").is_ok());
+/// ```
+pub struct FaultyEngine<E> {
+    inner: E,
+    fail_first: usize,
+    fail_every: Option<usize>,
+    latency: Option<Duration>,
+    make_error: fn() -> PrivacyEngineError,
+    prove_calls: AtomicUsize,
+    verify_calls: AtomicUsize,
+}
+
+impl<E: PrivacyEngine> FaultyEngine<E> {
+    /// Wrap `inner` with no faults configured
+    pub fn new(inner: E) -> Self {
+        Self {
+            inner,
+            fail_first: 0,
+            fail_every: None,
+            latency: None,
+            make_error: || PrivacyEngineError::BackendError("injected failure".into()),
+            prove_calls: AtomicUsize::new(0),
+            verify_calls: AtomicUsize::new(0),
+        }
+    }
+
+    /// Fail the first `n` calls of each method
+    pub fn fail_first(mut self, n: usize) -> Self {
+        self.fail_first = n;
+        self
+    }
+
+    /// Fail every `n`th call of each method (`n = 1` fails every call)
+    pub fn fail_every(mut self, n: usize) -> Self {
+        self.fail_every = Some(n.max(1));
+        self
+    }
+
+    /// Error returned for injected failures (defaults to `BackendError`)
+    pub fn with_error(mut self, make_error: fn() -> PrivacyEngineError) -> Self {
+        self.make_error = make_error;
+        self
+    }
+
+    /// Sleep for `latency` before every call
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// Number of times `prove` has been called (including injected failures)
+    pub fn prove_calls(&self) -> usize {
+        self.prove_calls.load(Ordering::SeqCst)
+    }
+
+    /// Number of times `verify` has been called (including injected failures)
+    pub fn verify_calls(&self) -> usize {
+        self.verify_calls.load(Ordering::SeqCst)
+    }
+
+    /// Record a call and decide whether to inject a failure
+    fn inject(&self, counter: &AtomicUsize) -> Result<(), PrivacyEngineError> {
+        let call = counter.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Some(latency) = self.latency {
+            std::thread::sleep(latency);
+        }
+
+        let fail = call <= self.fail_first || self.fail_every.is_some_and(|n| call.is_multiple_of(n));
+        if fail {
+            Err((self.make_error)())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<E: PrivacyEngine> PrivacyEngine for FaultyEngine<E> {
+    fn prove(&self, input: &[u8]) -> Result<ProofReceipt, PrivacyEngineError> {
+        self.inject(&self.prove_calls)?;
+        self.inner.prove(input)
+    }
+
+    fn verify(&self, receipt: &ProofReceipt) -> Result<bool, PrivacyEngineError> {
+        self.inject(&self.verify_calls)?;
+        self.inner.verify(receipt)
+    }
+
+    fn export_verifier(&self, chain: ChainType) -> Result<Vec<u8>, PrivacyEngineError> {
+        self.inner.export_verifier(chain)
+    }
+
+    fn export_verifier_with_report(
+        &self,
+        chain: ChainType,
+    ) -> Result<(Vec<u8>, crate::cost::VerifierReport), PrivacyEngineError> {
+        self.inner.export_verifier_with_report(chain)
+    }
+
+    fn vkey_hash(&self) -> [u8; 32] {
+        self.inner.vkey_hash()
+    }
+
+    fn backend_name(&self) -> &'static str {
+        self.inner.backend_name()
+    }
+
+    fn backend_version(&self) -> &'static str {
+        self.inner.backend_version()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_verifies_own_proofs_only() {
+        let engine = MockEngine::new();
+        let mut receipt = engine.prove(b"abc").unwrap();
+        assert!(engine.verify(&receipt).unwrap());
+
+        receipt.proof = vec![0];
+        assert!(!engine.verify(&receipt).unwrap());
+        assert_eq!(engine.verify_calls(), 2);
+    }
+
+    #[test]
+    fn test_with_failing_verify() {
+        let engine = MockEngine::with_failing_verify();
+        let receipt = engine.prove(b"abc").unwrap();
+        assert_eq!(receipt.proof, DEFAULT_MOCK_PROOF);
+        assert!(!engine.verify(&receipt).unwrap());
+    }
+
+    #[test]
+    fn test_backend_identity() {
+        let engine = MockEngine::new();
+        assert_eq!(engine.backend_name(), "mock");
+        assert_eq!(engine.backend_version(), env!("CARGO_PKG_VERSION"));
+
+        // Engines that don't override the defaults report "unknown"
+        struct Bare;
+        impl PrivacyEngine for Bare {
+            fn prove(&self, _: &[u8]) -> Result<ProofReceipt, PrivacyEngineError> {
+                Err(PrivacyEngineError::BackendError("unused".into()))
+            }
+            fn verify(&self, _: &ProofReceipt) -> Result<bool, PrivacyEngineError> {
+                Err(PrivacyEngineError::BackendError("unused".into()))
+            }
+            fn export_verifier(&self, _: ChainType) -> Result<Vec<u8>, PrivacyEngineError> {
+                Err(PrivacyEngineError::BackendError("unused".into()))
+            }
+        }
+        assert_eq!(Bare.backend_name(), "unknown");
+    }
+
+    #[test]
+    fn test_faulty_engine_fail_every() {
+        let engine = FaultyEngine::new(MockEngine::new())
+            .fail_every(3)
+            .with_error(|| PrivacyEngineError::ProvingFailed("boom".into()));
+
+        let outcomes: Vec<bool> = (0..6).map(|_| engine.prove(b"x").is_ok()).collect();
+        assert_eq!(outcomes, [true, true, false, true, true, false]);
+        assert!(engine.prove(b"x").is_ok());
+        assert_eq!(engine.prove_calls(), 7);
+        assert_eq!(engine.verify_calls(), 0);
+    }
+
+    #[test]
+    fn test_verify_fails() {
+        let engine = MockEngine::builder().verify_fails("boom").build();
+        let receipt = engine.prove(b"abc").unwrap();
+        assert!(matches!(engine.verify(&receipt), Err(PrivacyEngineError::VerificationFailed(_))));
+    }
+}
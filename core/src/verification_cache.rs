@@ -0,0 +1,212 @@
+//! # Verification Cache
+//!
+//! Memoizes `PrivacyEngine::verify` results so re-verifying the same receipt
+//! (e.g. an indexer reprocessing events) skips the pairing/STARK check.
+//!
+//! Entries are keyed by `(vkey_hash, ProofReceipt::fingerprint)`. Because the
+//! engine's current `vkey_hash()` is part of every lookup, a cached "valid"
+//! never survives a verifying-key rotation. Only successful verifications
+//! (`Ok(true)` / `Ok(false)`) are cached; errors are always retried.
+
+use crate::{ChainType, PrivacyEngine, PrivacyEngineError, ProofReceipt};
+use lru::LruCache;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+/// Default number of cached verification results
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// Cache key: verifying-key hash and receipt fingerprint
+type CacheKey = ([u8; 32], [u8; 32]);
+
+/// LRU cache of verification results
+pub struct VerificationCache {
+    entries: Mutex<LruCache<CacheKey, bool>>,
+}
+
+impl VerificationCache {
+    /// Create a cache holding up to `capacity` results (minimum 1)
+    pub fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self { entries: Mutex::new(LruCache::new(capacity)) }
+    }
+
+    /// Cached result for `receipt` under `vkey_hash`, if any
+    pub fn get(&self, vkey_hash: &[u8; 32], receipt: &ProofReceipt) -> Option<bool> {
+        self.lock().get(&(*vkey_hash, receipt.fingerprint())).copied()
+    }
+
+    /// Record the verification result for `receipt` under `vkey_hash`
+    pub fn insert(&self, vkey_hash: [u8; 32], receipt: &ProofReceipt, valid: bool) {
+        self.lock().put((vkey_hash, receipt.fingerprint()), valid);
+    }
+
+    /// Number of cached results
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Drop all cached results
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<CacheKey, bool>> {
+        // A poisoned cache only holds memoized results, so keep using it
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for VerificationCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_CAPACITY)
+    }
+}
+
+/// `PrivacyEngine` wrapper that serves repeat verifications from a cache
+///
+/// ## Example
+///
+/// ```ignore
+/// let verifier = CachingVerifier::new(sp1_backend);
+/// verifier.verify(&receipt)?; // full verification
+/// verifier.verify(&receipt)?; // served from cache
+/// ```
+pub struct CachingVerifier<E> {
+    inner: E,
+    cache: VerificationCache,
+}
+
+impl<E: PrivacyEngine> CachingVerifier<E> {
+    /// Wrap `inner` with a cache of `DEFAULT_CACHE_CAPACITY` results
+    pub fn new(inner: E) -> Self {
+        Self::with_cache(inner, VerificationCache::default())
+    }
+
+    /// Wrap `inner` with the given cache
+    pub fn with_cache(inner: E, cache: VerificationCache) -> Self {
+        Self { inner, cache }
+    }
+
+    /// The wrapped engine
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    /// The underlying cache
+    pub fn cache(&self) -> &VerificationCache {
+        &self.cache
+    }
+}
+
+impl<E: PrivacyEngine> PrivacyEngine for CachingVerifier<E> {
+    fn prove(&self, input: &[u8]) -> Result<ProofReceipt, PrivacyEngineError> {
+        self.inner.prove(input)
+    }
+
+    fn verify(&self, receipt: &ProofReceipt) -> Result<bool, PrivacyEngineError> {
+        let vkey_hash = self.inner.vkey_hash();
+        if let Some(valid) = self.cache.get(&vkey_hash, receipt) {
+            return Ok(valid);
+        }
+
+        let valid = self.inner.verify(receipt)?;
+        self.cache.insert(vkey_hash, receipt, valid);
+        Ok(valid)
+    }
+
+    fn export_verifier(&self, chain: ChainType) -> Result<Vec<u8>, PrivacyEngineError> {
+        self.inner.export_verifier(chain)
+    }
+
+    fn vkey_hash(&self) -> [u8; 32] {
+        self.inner.vkey_hash()
+    }
+
+    fn backend_name(&self) -> &'static str {
+        self.inner.backend_name()
+    }
+
+    fn backend_version(&self) -> &'static str {
+        self.inner.backend_version()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockEngine;
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    /// MockEngine whose verifying key can be rotated
+    struct RotatingEngine {
+        mock: MockEngine,
+        vkey: AtomicU8,
+    }
+
+    impl PrivacyEngine for RotatingEngine {
+        fn prove(&self, input: &[u8]) -> Result<ProofReceipt, PrivacyEngineError> {
+            self.mock.prove(input)
+        }
+
+        fn verify(&self, receipt: &ProofReceipt) -> Result<bool, PrivacyEngineError> {
+            self.mock.verify(receipt)
+        }
+
+        fn export_verifier(&self, chain: ChainType) -> Result<Vec<u8>, PrivacyEngineError> {
+            self.mock.export_verifier(chain)
+        }
+
+        fn vkey_hash(&self) -> [u8; 32] {
+            [self.vkey.load(Ordering::SeqCst); 32]
+        }
+    }
+
+    #[test]
+    fn test_repeat_verify_served_from_cache() {
+        let verifier = CachingVerifier::new(RotatingEngine { mock: MockEngine::new(), vkey: AtomicU8::new(1) });
+        let receipt = verifier.prove(b"values").unwrap();
+
+        assert!(verifier.verify(&receipt).unwrap());
+        assert!(verifier.verify(&receipt).unwrap());
+        assert_eq!(verifier.inner().mock.verify_calls(), 1);
+
+        // Metadata doesn't affect the fingerprint
+        assert!(verifier.verify(&receipt.minimized()).unwrap());
+        assert_eq!(verifier.inner().mock.verify_calls(), 1);
+
+        // Rotating the verifying key busts the cache
+        verifier.inner().vkey.store(2, Ordering::SeqCst);
+        assert!(verifier.verify(&receipt).unwrap());
+        assert_eq!(verifier.inner().mock.verify_calls(), 2);
+        assert_eq!(verifier.cache().len(), 2);
+    }
+
+    #[test]
+    fn test_errors_are_not_cached() {
+        let verifier = CachingVerifier::new(MockEngine::builder().verify_fails("offline").build());
+        let receipt = verifier.prove(b"values").unwrap();
+
+        assert!(verifier.verify(&receipt).is_err());
+        assert!(verifier.verify(&receipt).is_err());
+        assert_eq!(verifier.inner().verify_calls(), 2);
+        assert!(verifier.cache().is_empty());
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let verifier = CachingVerifier::with_cache(MockEngine::new(), VerificationCache::new(1));
+        let a = verifier.prove(b"a").unwrap();
+        let b = verifier.prove(b"b").unwrap();
+
+        verifier.verify(&a).unwrap();
+        verifier.verify(&b).unwrap();
+        verifier.verify(&a).unwrap();
+        assert_eq!(verifier.inner().verify_calls(), 3);
+    }
+}
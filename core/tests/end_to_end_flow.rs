@@ -1,8 +1,8 @@
 use universal_privacy_engine_core::{
-    PrivacyEngine, ProofReceipt, ProofType, ChainType, PrivacyEngineError,
+    PrivacyEngine, ProofType,
     data_source::{DataProvider, ZkInputBuilder, DataError},
+    testing::MockEngine,
 };
-use serde_json::json;
 use async_trait::async_trait;
 
 /// Mock Provider to simulate HTTP fetching without network calls
@@ -23,38 +23,6 @@ impl DataProvider for MockProvider {
     }
 }
 
-/// Mock Backend to simulate Sp1Prover behavior
-/// 
-/// We use this because we don't have a compiled SP1 guest ELF in this environment.
-/// This mock ensures the architectural flow is valid.
-struct MockSp1Backend;
-
-impl PrivacyEngine for MockSp1Backend {
-    fn prove(&self, input: &[u8]) -> Result<ProofReceipt, PrivacyEngineError> {
-        // Simulate proof generation
-        // In reality, this would run the SP1 zkVM
-        Ok(ProofReceipt {
-            proof_type: ProofType::ZkProof,
-            proof: vec![0xCA, 0xFE, 0xBA, 0xBE], // Fake proof bytes
-            public_values: input.to_vec(), // In this mock, we just echo input
-            metadata: b"mock_sp1_backend".to_vec(),
-        })
-    }
-
-    fn verify(&self, receipt: &ProofReceipt) -> Result<bool, PrivacyEngineError> {
-        // Verify the mock proof matches our expectations
-        if receipt.proof == vec![0xCA, 0xFE, 0xBA, 0xBE] {
-            Ok(true)
-        } else {
-            Ok(false)
-        }
-    }
-
-    fn export_verifier(&self, _chain: ChainType) -> Result<Vec<u8>, PrivacyEngineError> {
-        Ok(vec![])
-    }
-}
-
 #[tokio::test]
 async fn test_full_system_flow() {
     // 1. Fetch structured JSON data (mocked)
@@ -85,8 +53,8 @@ async fn test_full_system_flow() {
     let zk_input = builder.build();
 
     // 4. Generate ProofReceipt
-    // Use our Mock backend that implements PrivacyEngine trait
-    let backend = MockSp1Backend;
+    // Use the shared mock backend (we don't have a compiled SP1 guest ELF here)
+    let backend = MockEngine::new();
     
     println!("Generating proof for input size: {} bytes", zk_input.len());
     let receipt = backend.prove(&zk_input).expect("Proof generation failed");
@@ -99,5 +67,7 @@ async fn test_full_system_flow() {
     let is_valid = backend.verify(&receipt).expect("Verification failed");
     
     assert!(is_valid);
+    assert_eq!(backend.prove_calls(), 1);
+    assert_eq!(backend.verify_calls(), 1);
     println!("Integration test passed successfully!");
 }
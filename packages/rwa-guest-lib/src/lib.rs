@@ -0,0 +1,206 @@
+//! # RWA Guest Library
+//!
+//! `no_std` helpers shared by the zkVM guest program and host-side code, so the
+//! signed message layout and Merkle hashing are defined exactly once.
+//!
+//! The host signer (`RwaClaim::message_to_sign` in the core crate) and the
+//! guest verifier both call [`claim_message`], which guarantees they agree
+//! byte-for-byte.
+//!
+//! ## Merkle Hashing
+//!
+//! - Leaf: `sha256(0x00 || leaf_data)`
+//! - Node: `sha256(0x01 || left || right)`
+//!
+//! Domain-separating leaves from nodes prevents second-preimage attacks where
+//! an internal node is presented as a leaf.
+//!
+//! Balance-tree leaves use [`account_leaf`], whose data is
+//! `account_id (32) || balance (u64 LE)` (see [`ACCOUNT_LEAF_SCHEME`]). Binding
+//! the account id keeps two accounts with equal balances from sharing a leaf.
+
+#![no_std]
+
+use sha2::{Digest, Sha256};
+
+/// Length of a claim's replay-protection nonce
+pub const CLAIM_NONCE_LEN: usize = 16;
+
+/// Length of the message an institution signs over a claim
+pub const CLAIM_MESSAGE_LEN: usize = 8 + 8 + CLAIM_NONCE_LEN;
+
+/// Length of an asset identifier in a multi-asset claim
+pub const ASSET_ID_LEN: usize = 4;
+
+/// Length of the message an institution signs over one asset's balance
+pub const ASSET_BALANCE_MESSAGE_LEN: usize = ASSET_ID_LEN + 8 + 8 + CLAIM_NONCE_LEN;
+
+/// Prefix byte for Merkle leaf hashes
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+
+/// Prefix byte for Merkle internal node hashes
+pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+/// Build the message an institution signs to attest a balance.
+///
+/// `balance (u64 LE) || timestamp (u64 LE) || nonce`. The timestamp lets the
+/// guest enforce freshness and the nonce keeps two attestations of the same
+/// balance at the same second distinct, so old signatures can't be replayed.
+pub fn claim_message(balance: u64, timestamp: u64, nonce: &[u8; CLAIM_NONCE_LEN]) -> [u8; CLAIM_MESSAGE_LEN] {
+    let mut message = [0u8; CLAIM_MESSAGE_LEN];
+    message[..8].copy_from_slice(&balance.to_le_bytes());
+    message[8..16].copy_from_slice(&timestamp.to_le_bytes());
+    message[16..].copy_from_slice(nonce);
+    message
+}
+
+/// Build the message an institution signs to attest one asset's balance.
+///
+/// `asset_id || balance (u64 LE) || timestamp (u64 LE) || nonce`. Binding the
+/// asset id stops a signature for one asset being replayed against another
+/// with a lower balance; the claim-level timestamp and nonce stop it being
+/// replayed into a later claim, as with [`claim_message`].
+pub fn asset_balance_message(
+    asset_id: &[u8; ASSET_ID_LEN],
+    balance: u64,
+    timestamp: u64,
+    nonce: &[u8; CLAIM_NONCE_LEN],
+) -> [u8; ASSET_BALANCE_MESSAGE_LEN] {
+    let mut message = [0u8; ASSET_BALANCE_MESSAGE_LEN];
+    message[..ASSET_ID_LEN].copy_from_slice(asset_id);
+    message[ASSET_ID_LEN..ASSET_ID_LEN + 8].copy_from_slice(&balance.to_le_bytes());
+    message[ASSET_ID_LEN + 8..ASSET_ID_LEN + 16].copy_from_slice(&timestamp.to_le_bytes());
+    message[ASSET_ID_LEN + 16..].copy_from_slice(nonce);
+    message
+}
+
+/// Hash raw leaf data into a Merkle leaf.
+pub fn merkle_leaf(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([MERKLE_LEAF_PREFIX]);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Length of an account identifier in a balance-tree leaf
+pub const ACCOUNT_ID_LEN: usize = 32;
+
+/// Human-readable description of the balance-tree leaf encoding
+pub const ACCOUNT_LEAF_SCHEME: &str = "sha256(0x00 || account_id[32] || balance_u64_le)";
+
+/// Hash an account's balance into a balance-tree leaf.
+pub fn account_leaf(account_id: &[u8; ACCOUNT_ID_LEN], balance: u64) -> [u8; 32] {
+    let mut data = [0u8; ACCOUNT_ID_LEN + 8];
+    data[..ACCOUNT_ID_LEN].copy_from_slice(account_id);
+    data[ACCOUNT_ID_LEN..].copy_from_slice(&balance.to_le_bytes());
+    merkle_leaf(&data)
+}
+
+/// Hash two child nodes into their parent.
+pub fn merkle_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([MERKLE_NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Compute the root reached by walking `siblings` up from `leaf`.
+///
+/// Bit `i` of `index` gives the leaf's side at level `i`: 0 means the current
+/// node is the left child, 1 means it is the right child.
+pub fn merkle_root_from_path(leaf: [u8; 32], index: u64, siblings: &[[u8; 32]]) -> [u8; 32] {
+    siblings
+        .iter()
+        .enumerate()
+        .fold(leaf, |node, (level, sibling)| {
+            if (index >> level) & 1 == 0 {
+                merkle_node(&node, sibling)
+            } else {
+                merkle_node(sibling, &node)
+            }
+        })
+}
+
+/// Check that `leaf` at position `index` is included under `root`.
+pub fn verify_merkle_path(
+    leaf: [u8; 32],
+    index: u64,
+    siblings: &[[u8; 32]],
+    root: &[u8; 32],
+) -> bool {
+    siblings.len() <= 64 && merkle_root_from_path(leaf, index, siblings) == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_message_layout() {
+        let message = claim_message(0x0102_0304_0506_0708, 0x1122_3344_5566_7788, &[0xAB; CLAIM_NONCE_LEN]);
+        assert_eq!(&message[..8], &[8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(&message[8..16], &[0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]);
+        assert_eq!(&message[16..], &[0xAB; CLAIM_NONCE_LEN]);
+        assert_eq!(claim_message(0, 0, &[0; CLAIM_NONCE_LEN]), [0u8; CLAIM_MESSAGE_LEN]);
+    }
+
+    #[test]
+    fn test_asset_balance_message_layout() {
+        let nonce = [0xAB; CLAIM_NONCE_LEN];
+        let message = asset_balance_message(b"USDC", 0x0102_0304_0506_0708, 0x1122_3344_5566_7788, &nonce);
+        assert_eq!(&message[..ASSET_ID_LEN], b"USDC");
+        assert_eq!(&message[ASSET_ID_LEN..ASSET_ID_LEN + 8], &[8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(
+            &message[ASSET_ID_LEN + 8..ASSET_ID_LEN + 16],
+            &[0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]
+        );
+        assert_eq!(&message[ASSET_ID_LEN + 16..], &nonce);
+        assert_ne!(message, asset_balance_message(b"TBIL", 0x0102_0304_0506_0708, 0x1122_3344_5566_7788, &nonce));
+        assert_ne!(message, asset_balance_message(b"USDC", 0x0102_0304_0506_0708, 0x1122_3344_5566_7788, &[0; CLAIM_NONCE_LEN]));
+    }
+
+    #[test]
+    fn test_leaf_and_node_are_domain_separated() {
+        let a = [1u8; 32];
+        let mut concat = [0u8; 64];
+        concat[..32].copy_from_slice(&a);
+        concat[32..].copy_from_slice(&a);
+        assert_ne!(merkle_leaf(&concat), merkle_node(&a, &a));
+    }
+
+    #[test]
+    fn test_equal_balances_have_distinct_leaves() {
+        let alice = account_leaf(&[0xA1; ACCOUNT_ID_LEN], 1_000);
+        let bob = account_leaf(&[0xB0; ACCOUNT_ID_LEN], 1_000);
+        assert_ne!(alice, bob);
+
+        let root = merkle_node(&alice, &bob);
+        assert!(verify_merkle_path(alice, 0, &[bob], &root));
+        assert!(verify_merkle_path(bob, 1, &[alice], &root));
+        // Bob's balance can't be claimed under Alice's account id
+        assert!(!verify_merkle_path(account_leaf(&[0xA1; ACCOUNT_ID_LEN], 1_000), 1, &[alice], &root));
+    }
+
+    #[test]
+    fn test_merkle_walk_four_leaves() {
+        let leaves = [
+            merkle_leaf(b"a"),
+            merkle_leaf(b"b"),
+            merkle_leaf(b"c"),
+            merkle_leaf(b"d"),
+        ];
+        let left = merkle_node(&leaves[0], &leaves[1]);
+        let right = merkle_node(&leaves[2], &leaves[3]);
+        let root = merkle_node(&left, &right);
+
+        // Leaf "c" (index 2): sibling "d" then the left subtree
+        assert!(verify_merkle_path(leaves[2], 2, &[leaves[3], left], &root));
+        // Leaf "b" (index 1): sibling "a" then the right subtree
+        assert!(verify_merkle_path(leaves[1], 1, &[leaves[0], right], &root));
+
+        // Wrong index or wrong leaf fails
+        assert!(!verify_merkle_path(leaves[2], 3, &[leaves[3], left], &root));
+        assert!(!verify_merkle_path(merkle_leaf(b"x"), 2, &[leaves[3], left], &root));
+    }
+}